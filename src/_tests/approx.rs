@@ -0,0 +1,116 @@
+use super::*;
+
+#[test]
+fn approx_eq_abs_tol() {
+    let a = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    let b = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.001]]);
+
+    assert!(a.approx_eq(&b, 1e-2, 0.0));
+    assert!(!a.approx_eq(&b, 1e-4, 0.0));
+}
+
+#[test]
+fn approx_eq_rel_tol() {
+    let a = Matrix::new(vec![vec![1e9, 1e-9]]);
+    let b = Matrix::new(vec![vec![1e9 + 1.0, 2e-9]]);
+
+    // the absolute difference of the big entry is large but relatively tiny
+    assert!(!a.approx_eq(&b, 0.0, 1e-6));
+    assert!(a.approx_eq(&b, 1e-8, 1e-6));
+}
+
+#[test]
+fn approx_eq_shape_mismatch() {
+    let a = Matrix::new(vec![vec![1.0, 2.0]]);
+    let b = Matrix::new(vec![vec![1.0], vec![2.0]]);
+
+    assert!(!a.approx_eq(&b, 1.0, 1.0));
+}
+
+#[test]
+fn approx_eq_nan() {
+    let a = Matrix::new(vec![vec![f64::NAN]]);
+
+    assert!(!a.approx_eq(&a, 1.0, 1.0));
+}
+
+#[test]
+fn max_abs_diff() {
+    let a = Matrix::new(vec![vec![1.0, -2.0], vec![3.0, 4.0]]);
+    let b = Matrix::new(vec![vec![1.5, 2.0], vec![3.0, 4.25]]);
+
+    assert_eq!(4.0, a.max_abs_diff(&b));
+    assert_eq!(0.0, a.max_abs_diff(&a));
+}
+
+#[test]
+fn max_abs_diff_nan() {
+    let a = Matrix::new(vec![vec![f64::NAN, 2.0], vec![3.0, 4.0]]);
+    let b = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 9.0]]);
+
+    // NaN is not smaller than every other difference, so it is not dropped
+    assert!(a.max_abs_diff(&b).is_nan());
+    assert!(b.max_abs_diff(&a).is_nan());
+    assert!(a.max_abs_diff(&a).is_nan());
+    assert!(!a.approx_eq(&a, 0.0, 0.0));
+}
+
+#[test]
+#[should_panic]
+fn max_abs_diff_shape_mismatch() {
+    let a = Matrix::new(vec![vec![1.0, 2.0]]);
+    let b = Matrix::new(vec![vec![1.0], vec![2.0]]);
+
+    a.max_abs_diff(&b);
+}
+
+#[test]
+fn float_mul_and_strass() {
+    let a = Matrix::from_list(3, 3, (1..=9).map(|x| x as f64 / 7.0).collect());
+    let b = Matrix::from_list(3, 3, (1..=9).map(|x| 1.0 / x as f64).collect());
+
+    let naive = a.clone() * b.clone();
+
+    assert!(naive.approx_eq(&a.strass(&b), 1e-12, 1e-12));
+}
+
+#[test]
+fn strass_rounding_ill_conditioned() {
+    // strassen's m1 = (a11 + a22)(b11 + b22) is huge and has to cancel down to the small
+    // c22, the naive product never forms it. f32 inputs are checked against an f64 product
+    let a = Matrix::new(vec![vec![1e4f32, 0.3], vec![1.7, 1.1]]);
+    let b = Matrix::new(vec![vec![1e4f32, 1.3], vec![0.7, 0.9]]);
+
+    let reference = a.map(f64::from) * b.map(f64::from);
+    let naive = (a.clone() * b.clone()).map(f64::from);
+    let strass = a.strass(&b).map(f64::from);
+
+    let eps = f32::EPSILON as f64;
+
+    // the naive product is accurate entry by entry
+    assert!(naive.approx_eq(&reference, 0.0, 4.0 * eps));
+
+    // strassen loses every digit of c22 = 3.2
+    assert!(!strass.approx_eq(&reference, 0.0, 0.1));
+    assert!(strass.max_abs_diff(&reference) > 10.0 * naive.max_abs_diff(&reference));
+
+    // but it stays within its normwise bound, a small multiple of eps * |A| * |B|
+    let bound = 16.0 * eps * 1e4 * 1e4;
+    assert!(strass.max_abs_diff(&reference) < bound);
+}
+
+#[test]
+fn strass_rounding_well_conditioned() {
+    // with entries of similar size both products agree to working precision
+    let a = Matrix::from_list(4, 4, (1..=16).map(|x| 1.0 + x as f32 / 16.0).collect());
+    let b = Matrix::from_list(4, 4, (1..=16).map(|x| 2.0 - x as f32 / 32.0).collect());
+
+    let reference = a.map(f64::from) * b.map(f64::from);
+    let naive = (a.clone() * b.clone()).map(f64::from);
+    let strass = a.strass(&b).map(f64::from);
+
+    let eps = f32::EPSILON as f64;
+
+    assert!(naive.approx_eq(&reference, 0.0, 8.0 * eps));
+    assert!(strass.approx_eq(&reference, 0.0, 32.0 * eps));
+}
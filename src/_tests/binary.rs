@@ -0,0 +1,110 @@
+use super::*;
+use crate::matrix;
+
+fn encode<T: BinaryElement>(a: &Matrix<T>) -> Vec<u8> {
+    let mut out = Vec::new();
+    a.write_to(&mut out).unwrap();
+    out
+}
+
+#[test]
+fn layout() {
+    let bytes = encode(&matrix![1i32, -2, 3]);
+
+    assert_eq!(b"MTRX\x01<\x01\x00", &bytes[..8]);
+    assert_eq!(1, u64::from_le_bytes(bytes[8..16].try_into().unwrap()));
+    assert_eq!(3, u64::from_le_bytes(bytes[16..24].try_into().unwrap()));
+    assert_eq!(&(-2i32).to_le_bytes(), &bytes[28..32]);
+    assert_eq!(HEADER_LEN + 3 * 4 + CHECKSUM_LEN, bytes.len());
+
+    let crc = crc32fast::hash(&bytes[..bytes.len() - 4]);
+    assert_eq!(&crc.to_le_bytes(), &bytes[bytes.len() - 4..]);
+}
+
+#[test]
+fn round_trip() {
+    let a = matrix![1, -2, 3; 4, 5, isize::MIN];
+    assert_eq!(a, Matrix::read_from(encode(&a).as_slice()).unwrap());
+
+    let b = matrix![0.5, -1e300; f64::MAX, 0.0];
+    assert_eq!(b, Matrix::read_from(encode(&b).as_slice()).unwrap());
+
+    let c = matrix![1.5f32; -2.25];
+    assert_eq!(c, Matrix::read_from(encode(&c).as_slice()).unwrap());
+
+    // isize and i64 share a tag
+    let d: Matrix<i64> = Matrix::read_from(encode(&a).as_slice()).unwrap();
+    assert_eq!(a.map(|x| x as i64), d);
+}
+
+#[test]
+fn truncated() {
+    let bytes = encode(&matrix![1isize, 2; 3, 4]);
+
+    for len in [0, 5, HEADER_LEN, HEADER_LEN + 12, bytes.len() - 1] {
+        assert!(
+            matches!(
+                Matrix::<isize>::read_from(&bytes[..len]),
+                Err(BinaryError::Truncated)
+            ),
+            "length {}",
+            len
+        );
+    }
+}
+
+#[test]
+fn corrupt_data() {
+    let mut bytes = encode(&matrix![1isize, 2; 3, 4]);
+    bytes[HEADER_LEN + 9] ^= 0x40;
+
+    assert!(matches!(
+        Matrix::<isize>::read_from(bytes.as_slice()),
+        Err(BinaryError::ChecksumMismatch { .. })
+    ));
+}
+
+#[test]
+fn bad_header() {
+    let bytes = encode(&matrix![1isize, 2; 3, 4]);
+    let read = |edit: &dyn Fn(&mut Vec<u8>)| {
+        let mut bytes = bytes.clone();
+        edit(&mut bytes);
+        Matrix::<isize>::read_from(bytes.as_slice())
+    };
+
+    assert!(matches!(read(&|b| b[0] = b'N'), Err(BinaryError::BadMagic)));
+    assert!(matches!(
+        read(&|b| b[4] = 9),
+        Err(BinaryError::UnsupportedVersion(9))
+    ));
+    assert!(matches!(
+        read(&|b| b[5] = b'>'),
+        Err(BinaryError::UnsupportedByteOrder(b'>'))
+    ));
+    assert!(matches!(
+        read(&|b| b[16..24].copy_from_slice(&0u64.to_le_bytes())),
+        Err(BinaryError::InvalidShape { rows: 2, cols: 0 })
+    ));
+    assert!(matches!(
+        read(&|b| b[8..16].copy_from_slice(&u64::MAX.to_le_bytes())),
+        Err(BinaryError::InvalidShape { .. })
+    ));
+    // a huge but addressable shape runs out of data instead of allocating it
+    assert!(matches!(
+        read(&|b| b[16..24].copy_from_slice(&(1u64 << 40).to_le_bytes())),
+        Err(BinaryError::Truncated)
+    ));
+}
+
+#[test]
+fn element_mismatch() {
+    let bytes = encode(&matrix![1.0, 2.0]);
+
+    match Matrix::<i32>::read_from(bytes.as_slice()) {
+        Err(err @ BinaryError::ElementMismatch { .. }) => {
+            assert_eq!("expected i32 elements, found f64", err.to_string())
+        }
+        other => panic!("expected an element mismatch, found {:?}", other),
+    }
+}
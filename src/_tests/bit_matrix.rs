@@ -0,0 +1,187 @@
+use super::*;
+
+fn random(rows: usize, cols: usize, seed: u64) -> BitMatrix {
+    // small lcg so the tests are deterministic
+    let mut state = seed;
+    let list = (0..rows * cols)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            state >> 63 == 1
+        })
+        .collect();
+
+    BitMatrix::from_list(rows, cols, list)
+}
+
+fn naive_mul(a: &BitMatrix, b: &BitMatrix) -> BitMatrix {
+    BitMatrix::from_matrix(&(a.to_matrix() * b.to_matrix()))
+}
+
+#[test]
+fn from_list_and_index() {
+    let a = BitMatrix::from_list(2, 3, vec![true, false, true, false, true, true]);
+
+    assert!(a[[1, 1]]);
+    assert!(!a[[1, 2]]);
+    assert!(a[[1, 3]]);
+    assert!(!a[[2, 1]]);
+    assert!(a[[2, 2]]);
+    assert!(a[[2, 3]]);
+}
+
+#[test]
+fn from_matrix_reduces_mod_2() {
+    let mat = Matrix::from_list(2, 2, vec![1, 2, -3, 4]);
+
+    let a = BitMatrix::from_matrix(&mat);
+
+    assert_eq!(Matrix::from_list(2, 2, vec![1, 0, 1, 0]), a.to_matrix());
+}
+
+#[test]
+fn set_past_word_boundary() {
+    let mut a = BitMatrix::zeroes(2, 130);
+    a.set(2, 65, true);
+    a.set(2, 130, true);
+
+    assert!(a[[2, 65]]);
+    assert!(a[[2, 130]]);
+    assert!(!a[[1, 65]]);
+
+    a.set(2, 65, false);
+
+    assert!(!a[[2, 65]]);
+}
+
+#[test]
+#[should_panic]
+fn index_out_of_bounds() {
+    let a = BitMatrix::zeroes(2, 2);
+
+    let _ = a[[3, 1]];
+}
+
+#[test]
+fn add_is_xor() {
+    let a = BitMatrix::from_list(1, 4, vec![true, true, false, false]);
+    let b = BitMatrix::from_list(1, 4, vec![true, false, true, false]);
+
+    let mat = BitMatrix::from_list(1, 4, vec![false, true, true, false]);
+
+    assert_eq!(mat, a.clone() + b.clone());
+    assert_eq!(mat, a - b);
+}
+
+#[test]
+fn m4rm_2x2() {
+    let a = BitMatrix::from_list(2, 2, vec![true, true, false, true]);
+
+    let mat = BitMatrix::from_list(2, 2, vec![true, false, false, true]);
+
+    assert_eq!(mat, a.m4rm(&a));
+}
+
+#[test]
+fn m4rm_matches_naive() {
+    let a = random(70, 131, 1);
+    let b = random(131, 67, 2);
+
+    assert_eq!(naive_mul(&a, &b), a.m4rm(&b));
+}
+
+#[test]
+fn strass_matches_naive() {
+    let a = random(100, 150, 3);
+    let b = random(150, 90, 4);
+
+    assert_eq!(naive_mul(&a, &b), a.strass(&b));
+}
+
+#[test]
+fn mul_large_uses_strass() {
+    let a = random(600, 600, 5);
+    let b = random(600, 600, 6);
+
+    assert_eq!(a.m4rm(&b), a * b);
+}
+
+#[test]
+fn ident_mul() {
+    let a = random(40, 40, 7);
+
+    assert_eq!(a, a.clone() * BitMatrix::identity(40));
+}
+
+#[test]
+fn transpose() {
+    let a = random(3, 70, 8);
+
+    assert_eq!(a, a.transpose().transpose());
+    assert_eq!(a[[2, 69]], a.transpose()[[69, 2]]);
+}
+
+#[test]
+fn rank() {
+    let a = BitMatrix::from_list(
+        3,
+        3,
+        vec![true, true, false, false, true, true, true, false, true],
+    );
+
+    // the third row is the sum of the first two
+    assert_eq!(2, a.rank());
+    assert_eq!(40, BitMatrix::identity(40).rank());
+    assert_eq!(0, BitMatrix::zeroes(3, 5).rank());
+}
+
+#[test]
+fn inverse() {
+    let a = BitMatrix::from_list(
+        3,
+        3,
+        vec![true, true, false, false, true, true, false, false, true],
+    );
+
+    let inv = a.inverse().unwrap();
+
+    assert_eq!(BitMatrix::identity(3), a.clone() * inv.clone());
+    assert_eq!(BitMatrix::identity(3), inv * a);
+}
+
+#[test]
+fn inverse_random() {
+    // roughly 29% of random square matrices over GF(2) are invertible, try until one is
+    let (a, inv) = (0..100)
+        .map(|seed| random(100, 100, seed))
+        .find_map(|a| a.inverse().ok().map(|inv| (a, inv)))
+        .unwrap();
+
+    assert_eq!(BitMatrix::identity(100), a * inv);
+}
+
+#[test]
+fn inverse_singular() {
+    let a = BitMatrix::from_list(2, 2, vec![true, true, true, true]);
+
+    assert_eq!(Err(MatrixError::Singular), a.inverse());
+}
+
+#[test]
+fn nullspace() {
+    let a = random(20, 90, 9);
+
+    let null = a.nullspace();
+
+    assert_eq!(90 - a.rank(), null.rows());
+    assert_eq!(null.rows(), null.rank());
+    assert_eq!(BitMatrix::zeroes(20, null.rows()), a * null.transpose());
+}
+
+#[test]
+fn nullspace_full_rank() {
+    let null = BitMatrix::identity(5).nullspace();
+
+    assert_eq!(0, null.rows());
+}
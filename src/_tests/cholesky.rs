@@ -0,0 +1,125 @@
+use super::*;
+
+fn covariance() -> Matrix<f64> {
+    Matrix::new(vec![
+        vec![4.0, 2.0, 0.6, -1.0],
+        vec![2.0, 5.0, 1.0, 0.5],
+        vec![0.6, 1.0, 3.0, 0.2],
+        vec![-1.0, 0.5, 0.2, 2.0],
+    ])
+}
+
+#[test]
+fn cholesky_2x2() {
+    let a = Matrix::new(vec![vec![4.0, 2.0], vec![2.0, 5.0]]);
+
+    let l = a.cholesky().unwrap();
+
+    assert_eq!(Matrix::new(vec![vec![2.0, 0.0], vec![1.0, 2.0]]), l);
+}
+
+#[test]
+fn cholesky_reconstructs() {
+    let a = covariance();
+
+    let l = a.cholesky().unwrap();
+
+    assert!((0..4).all(|i| (i + 1..4).all(|j| l.cells[i][j] == 0.0)));
+    assert!((l.clone() * l.transpose()).approx_eq(&a, 1e-12, 0.0));
+}
+
+#[test]
+fn cholesky_not_positive_definite() {
+    let a = Matrix::new(vec![
+        vec![1.0, 2.0, 0.0],
+        vec![2.0, 1.0, 0.0],
+        vec![0.0, 0.0, 1.0],
+    ]);
+
+    assert_eq!(
+        Err(MatrixError::NotPositiveDefinite { pivot: 2 }),
+        a.cholesky()
+    );
+}
+
+#[test]
+fn cholesky_semidefinite_fails() {
+    let a = Matrix::new(vec![vec![1.0, 1.0], vec![1.0, 1.0]]);
+
+    assert_eq!(
+        Err(MatrixError::NotPositiveDefinite { pivot: 2 }),
+        a.cholesky()
+    );
+}
+
+#[test]
+fn cholesky_solve_matches_product() {
+    let a = covariance();
+    let x = Matrix::new(vec![
+        vec![1.0, 0.0],
+        vec![-2.0, 1.0],
+        vec![0.5, 3.0],
+        vec![4.0, -1.0],
+    ]);
+    let b = a.clone() * x.clone();
+
+    assert!(a.cholesky_solve(&b).unwrap().approx_eq(&x, 1e-12, 0.0));
+}
+
+#[test]
+fn ldl_reconstructs() {
+    let a = covariance();
+
+    let (l, d) = a.ldl().unwrap();
+
+    assert!((0..4).all(|i| l.cells[i][i] == 1.0));
+    assert!((l.clone() * d * l.transpose()).approx_eq(&a, 1e-12, 0.0));
+}
+
+#[test]
+fn ldl_semidefinite() {
+    // rank 2, built as B B^T
+    let b = Matrix::new(vec![vec![1.0, 0.0], vec![2.0, 1.0], vec![3.0, 1.0]]);
+    let a = b.clone() * b.transpose();
+
+    let (l, d) = a.ldl().unwrap();
+
+    assert_eq!(0.0, d.cells[2][2]);
+    assert!((l.clone() * d * l.transpose()).approx_eq(&a, 1e-12, 0.0));
+}
+
+#[test]
+fn ldl_indefinite() {
+    let a = Matrix::new(vec![vec![1.0, 2.0], vec![2.0, 1.0]]);
+
+    assert_eq!(Err(MatrixError::NotPositiveDefinite { pivot: 2 }), a.ldl());
+}
+
+#[test]
+fn ldl_zero_pivot_with_coupling() {
+    let a = Matrix::new(vec![vec![0.0, 1.0], vec![1.0, 1.0]]);
+
+    assert_eq!(Err(MatrixError::NotPositiveDefinite { pivot: 1 }), a.ldl());
+}
+
+#[test]
+fn not_positive_definite_display() {
+    assert_eq!(
+        "matrix is not positive definite at pivot 3",
+        MatrixError::NotPositiveDefinite { pivot: 3 }.to_string()
+    );
+}
+
+#[test]
+fn cholesky_mixed_units() {
+    // a valid covariance whose variances differ by eighteen orders of magnitude
+    let a = Matrix::new(vec![vec![1e12, 0.0], vec![0.0, 1e-6]]);
+
+    let l = a.cholesky().unwrap();
+
+    assert_eq!(Matrix::new(vec![vec![1e6, 0.0], vec![0.0, 1e-3]]), l);
+    assert_eq!(
+        Matrix::new(vec![vec![1e12, 0.0], vec![0.0, 1e-6]]),
+        a.ldl().unwrap().1
+    );
+}
@@ -0,0 +1,118 @@
+use super::*;
+
+fn parse_error(text: &str, options: &CsvOptions) -> (usize, usize, String) {
+    match Matrix::<isize>::from_csv(text.as_bytes(), options) {
+        Err(ReadError::Parse {
+            line,
+            column,
+            message,
+        }) => (line, column, message),
+        other => panic!("expected a parse error, found {:?}", other),
+    }
+}
+
+#[test]
+fn read_csv() {
+    let text = "1,2,3\n4, 5 ,6\r\n";
+
+    let a = Matrix::<isize>::from_csv(text.as_bytes(), &CsvOptions::default()).unwrap();
+
+    assert_eq!(Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]]), a);
+}
+
+#[test]
+fn read_tsv_with_header_and_comments() {
+    let text = "# exported covariance\nx\ty\n\n1.5\t-2\n# midway\n0.25\t1e3\n";
+    let options = CsvOptions {
+        header: true,
+        ..CsvOptions::tsv()
+    };
+
+    let a = Matrix::<f64>::from_csv(text.as_bytes(), &options).unwrap();
+
+    assert_eq!(Matrix::new(vec![vec![1.5, -2.0], vec![0.25, 1000.0]]), a);
+}
+
+#[test]
+fn read_custom_comment_and_delimiter() {
+    let text = "% note\n1;2\n3;4\n";
+    let options = CsvOptions {
+        delimiter: ';',
+        header: false,
+        comment: Some('%'),
+    };
+
+    let a = Matrix::<isize>::from_csv(text.as_bytes(), &options).unwrap();
+
+    assert_eq!(Matrix::new(vec![vec![1, 2], vec![3, 4]]), a);
+}
+
+#[test]
+fn ragged_rows() {
+    let options = CsvOptions::default();
+
+    // too many fields points at the first extra one
+    assert_eq!(
+        (2, 7, "expected 2 fields, found 3".to_string()),
+        parse_error("1,2\n3, 4, 5\n", &options)
+    );
+    // too few points just past the end of the line
+    assert_eq!(
+        (3, 2, "expected 2 fields, found 1".to_string()),
+        parse_error("1,2\n3,4\n5\n", &options)
+    );
+    // the header sets the width too
+    let header = CsvOptions {
+        header: true,
+        ..CsvOptions::default()
+    };
+    assert_eq!(
+        (2, 5, "expected 2 fields, found 3".to_string()),
+        parse_error("a,b\n1,2,3\n", &header)
+    );
+}
+
+#[test]
+fn invalid_fields() {
+    let options = CsvOptions::default();
+
+    assert_eq!(
+        (2, 4, "invalid number `x7`".to_string()),
+        parse_error("1,2\n3, x7\n", &options)
+    );
+    assert_eq!(
+        (1, 3, "empty field".to_string()),
+        parse_error("1,,3\n", &options)
+    );
+    assert_eq!(
+        (1, 1, "invalid number `1.5`".to_string()),
+        parse_error("1.5\n", &options)
+    );
+    assert_eq!(
+        (3, 1, "no data rows".to_string()),
+        parse_error("# nothing\n\n", &options)
+    );
+}
+
+#[test]
+fn write_csv() {
+    let a = Matrix::new(vec![vec![1, -2], vec![30, 4]]);
+    let mut out = Vec::new();
+
+    a.to_csv(&mut out).unwrap();
+
+    assert_eq!("1,-2\n30,4\n", String::from_utf8(out).unwrap());
+}
+
+#[test]
+fn write_tsv_round_trip() {
+    let a = Matrix::new(vec![vec![0.5, -1e-3, 2.0], vec![3.25, 0.0, 1e20]]);
+    let mut out = Vec::new();
+
+    a.to_csv_with_delimiter(&mut out, '\t').unwrap();
+
+    assert_eq!(
+        a,
+        Matrix::from_csv(out.as_slice(), &CsvOptions::tsv()).unwrap()
+    );
+}
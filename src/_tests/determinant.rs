@@ -0,0 +1,67 @@
+use super::*;
+use crate::rational::Rational;
+
+#[test]
+fn determinant_2x2() {
+    let a = Matrix::from_list(2, 2, vec![1, 2, 3, 4]);
+
+    assert_eq!(Ok(-2), a.determinant());
+}
+
+#[test]
+fn determinant_3x3() {
+    let a = Matrix::from_list(3, 3, vec![2, 3, 6, 5, 1, 4, 3, 2, 1]);
+
+    assert_eq!(Ok(49), a.determinant());
+}
+
+#[test]
+fn determinant_needs_pivoting() {
+    let a = Matrix::from_list(3, 3, vec![0, 1, 2, 1, 0, 3, 4, -3, 8]);
+
+    assert_eq!(Ok(-2), a.determinant());
+}
+
+#[test]
+fn determinant_singular() {
+    let a = Matrix::from_list(3, 3, (1..=9).collect());
+
+    assert_eq!(Ok(0), a.determinant());
+}
+
+#[test]
+fn determinant_identity() {
+    assert_eq!(Ok(1), Matrix::<isize>::identity(5).determinant());
+}
+
+#[test]
+fn determinant_overflow() {
+    let a = Matrix::from_list(2, 2, vec![isize::MAX, 2, 2, isize::MAX]);
+
+    assert_eq!(Err(MatrixError::Overflow), a.determinant());
+}
+
+#[test]
+#[should_panic]
+fn determinant_not_square() {
+    let a = Matrix::from_list(2, 3, (1..=6).collect());
+
+    let _ = a.determinant();
+}
+
+#[test]
+fn determinant_rational() {
+    let r = Rational::new;
+    let a = Matrix::from_list(2, 2, vec![r(1, 2), r(1, 3), r(1, 4), r(1, 5)]);
+
+    assert_eq!(r(1, 60), a.determinant());
+}
+
+#[test]
+fn determinant_rational_matches_integer() {
+    let a = Matrix::from_list(3, 3, vec![0, 1, 2, 1, 0, 3, 4, -3, 8]);
+
+    let det = a.map(Rational::from).determinant();
+
+    assert_eq!(Rational::from(a.determinant().unwrap()), det);
+}
@@ -0,0 +1,71 @@
+use super::*;
+use crate::matrix;
+
+#[test]
+fn right_aligned_columns() {
+    let a = matrix![1, -20, 3; 400, 5, 6];
+
+    assert_eq!("[[  1 -20 3]\n [400   5 6]]", a.to_string());
+}
+
+#[test]
+fn float_precision() {
+    let a = matrix![1.0, 0.5; -2.25, 10.125];
+    let options = PrintOptions {
+        precision: Some(1),
+        ..PrintOptions::default()
+    };
+
+    assert_eq!("[[    1    0.5]\n [-2.25 10.125]]", a.to_string());
+    assert_eq!(
+        "[[ 1.0  0.5]\n [-2.2 10.1]]",
+        a.display(&options).to_string()
+    );
+    // the format string wins over the options
+    assert_eq!(
+        "[[ 1.000  0.500]\n [-2.250 10.125]]",
+        format!("{:.3}", a.display(&options))
+    );
+}
+
+#[test]
+fn summarise_large() {
+    let a = Matrix::from_list(6, 8, (0..48).collect());
+    let options = PrintOptions {
+        threshold: 20,
+        edge_items: 2,
+        ..PrintOptions::default()
+    };
+
+    assert_eq!(
+        "[[ 0  1 ...  6  7]\n [ 8  9 ... 14 15]\n ...\n [32 33 ... 38 39]\n [40 41 ... 46 47]]",
+        a.display(&options).to_string()
+    );
+    // small matrices are printed in full
+    assert_eq!(6, a.to_string().lines().count());
+}
+
+#[test]
+fn blocks_outlined() {
+    let a = Blocks::from_matrix(Matrix::from_list(4, 4, (1..=16).collect()));
+
+    assert_eq!(
+        "[[ 1  2 |  3  4]\n [ 5  6 |  7  8]\n [------+------]\n [ 9 10 | 11 12]\n [13 14 | 15 16]]",
+        a.to_string()
+    );
+}
+
+#[test]
+fn blocks_summary_keeps_whole_blocks() {
+    let a = Blocks::from_matrix(Matrix::from_list(6, 6, (0..36).collect()));
+    let options = PrintOptions {
+        threshold: 10,
+        edge_items: 1,
+        ..PrintOptions::default()
+    };
+
+    assert_eq!(
+        "[[ 0  1 ...  4  5]\n [ 6  7 ... 10 11]\n ...\n [24 25 ... 28 29]\n [30 31 ... 34 35]]",
+        a.display(&options).to_string()
+    );
+}
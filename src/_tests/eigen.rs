@@ -0,0 +1,259 @@
+use super::*;
+
+fn diag(values: &[f64]) -> Matrix<f64> {
+    let mut out = Matrix::zeroes(values.len(), values.len());
+
+    for (i, &value) in values.iter().enumerate() {
+        out.cells[i][i] = value;
+    }
+
+    out
+}
+
+fn symmetric(n: usize) -> Matrix<f64> {
+    let list = (0..n * n)
+        .map(|idx| {
+            let (r, c) = (idx / n, idx % n);
+            ((r * c + r + c) % 7) as f64 - 3.0
+        })
+        .collect();
+
+    Matrix::from_list(n, n, list)
+}
+
+#[test]
+fn symmetric_eigen_2x2() {
+    let a = Matrix::new(vec![vec![2.0, 1.0], vec![1.0, 2.0]]);
+
+    let (values, v) = a.symmetric_eigen();
+
+    assert!((values[0] - 1.0).abs() < 1e-12);
+    assert!((values[1] - 3.0).abs() < 1e-12);
+    assert!((v.transpose() * v).approx_eq(&Matrix::identity(2), 1e-12, 0.0));
+}
+
+#[test]
+fn symmetric_eigen_reconstructs() {
+    for n in [1, 3, 6, 10] {
+        let a = symmetric(n);
+
+        let (values, v) = a.symmetric_eigen();
+
+        assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!((v.transpose() * v.clone()).approx_eq(&Matrix::identity(n), 1e-12, 0.0));
+        assert!((v.clone() * diag(&values) * v.transpose()).approx_eq(&a, 1e-10, 0.0));
+    }
+}
+
+#[test]
+fn symmetric_eigen_diagonal() {
+    let a = diag(&[3.0, -1.0, 2.0]);
+
+    let (values, v) = a.symmetric_eigen();
+
+    assert_eq!(vec![-1.0, 2.0, 3.0], values);
+    assert_eq!(
+        Matrix::new(vec![
+            vec![0.0, 0.0, 1.0],
+            vec![1.0, 0.0, 0.0],
+            vec![0.0, 1.0, 0.0],
+        ]),
+        v
+    );
+}
+
+#[test]
+fn symmetric_eigen_repeated() {
+    // J - I for the all ones J has eigenvalues -1, -1, 2
+    let a = Matrix::new(vec![
+        vec![0.0, 1.0, 1.0],
+        vec![1.0, 0.0, 1.0],
+        vec![1.0, 1.0, 0.0],
+    ]);
+
+    let (values, v) = a.symmetric_eigen();
+
+    assert!((values[0] + 1.0).abs() < 1e-12);
+    assert!((values[1] + 1.0).abs() < 1e-12);
+    assert!((values[2] - 2.0).abs() < 1e-12);
+    assert!((v.clone() * diag(&values) * v.transpose()).approx_eq(&a, 1e-12, 0.0));
+}
+
+#[test]
+fn symmetric_eigen_f32() {
+    let a: Matrix<f32> = Matrix::new(vec![vec![4.0, 1.0], vec![1.0, 3.0]]);
+
+    let (values, v) = a.symmetric_eigen();
+    let d = Matrix::new(vec![vec![values[0], 0.0], vec![0.0, values[1]]]);
+
+    assert!((v.clone() * d * v.transpose()).approx_eq(&a, 1e-5, 0.0));
+}
+
+#[test]
+#[should_panic]
+fn symmetric_eigen_not_square() {
+    Matrix::<f64>::zeroes(2, 3).symmetric_eigen();
+}
+
+fn general(n: usize) -> Matrix<f64> {
+    let list = (0..n * n)
+        .map(|idx| {
+            let (r, c) = (idx / n, idx % n);
+            ((r * 5 + c * 3 + r * c) % 11) as f64 - 5.0
+        })
+        .collect();
+
+    Matrix::from_list(n, n, list)
+}
+
+fn companion(coefficients: &[f64]) -> Matrix<f64> {
+    // monic polynomial x^n + c_{n-1} x^{n-1} + ... + c_0, coefficients from c_0 up
+    let n = coefficients.len();
+    let mut out = Matrix::zeroes(n, n);
+
+    for i in 1..n {
+        out.cells[i][i - 1] = 1.0;
+    }
+    for (i, &c) in coefficients.iter().enumerate() {
+        out.cells[i][n - 1] = -c;
+    }
+
+    out
+}
+
+fn sorted(mut values: Vec<Complex<f64>>) -> Vec<Complex<f64>> {
+    values.sort_by(|a, b| {
+        (a.re, a.im)
+            .partial_cmp(&(b.re, b.im))
+            .unwrap_or(Ordering::Equal)
+    });
+    values
+}
+
+fn close(expected: &[(f64, f64)], found: &[Complex<f64>], tol: f64) -> bool {
+    expected.len() == found.len()
+        && expected
+            .iter()
+            .zip(found)
+            .all(|(&(re, im), z)| (re - z.re).abs() < tol && (im - z.im).abs() < tol)
+}
+
+#[test]
+fn hessenberg_reconstructs() {
+    let a = general(6);
+
+    let (q, h) = a.hessenberg();
+
+    assert!((0..6usize).all(|i| (0..i.saturating_sub(1)).all(|j| h.cells[i][j] == 0.0)));
+    assert!((q.transpose() * q.clone()).approx_eq(&Matrix::identity(6), 1e-12, 0.0));
+    assert!((q.clone() * h * q.transpose()).approx_eq(&a, 1e-12, 0.0));
+}
+
+#[test]
+fn schur_reconstructs() {
+    for n in [1, 2, 5, 8, 12] {
+        let a = general(n);
+
+        let (q, t) = a.schur().unwrap();
+
+        // quasi upper triangular, with no two consecutive subdiagonal entries
+        assert!((0..n).all(|i| (0..i.saturating_sub(1)).all(|j| t.cells[i][j] == 0.0)));
+        assert!((2..n).all(|i| t.cells[i][i - 1] == 0.0 || t.cells[i - 1][i - 2] == 0.0));
+        assert!((q.transpose() * q.clone()).approx_eq(&Matrix::identity(n), 1e-12, 0.0));
+        assert!((q.clone() * t * q.transpose()).approx_eq(&a, 1e-10, 0.0));
+    }
+}
+
+#[test]
+fn eigenvalues_rotation() {
+    let a = Matrix::new(vec![vec![0.0, -1.0], vec![1.0, 0.0]]);
+
+    let values = a.eigenvalues().unwrap();
+
+    assert!(close(&[(0.0, 1.0), (0.0, -1.0)], &values, 1e-12));
+}
+
+#[test]
+fn eigenvalues_companion() {
+    // (x - 1)(x - 2)(x - 3)(x^2 + 1) = x^5 - 6x^4 + 12x^3 - 12x^2 + 11x - 6
+    let a = companion(&[-6.0, 11.0, -12.0, 12.0, -6.0]);
+
+    let values = sorted(a.eigenvalues().unwrap());
+
+    assert!(close(
+        &[(0.0, -1.0), (0.0, 1.0), (1.0, 0.0), (2.0, 0.0), (3.0, 0.0)],
+        &values,
+        1e-9
+    ));
+}
+
+#[test]
+fn eigenvalues_match_symmetric() {
+    let a = symmetric(7);
+
+    let values = a.eigenvalues().unwrap();
+    let (expected, _) = a.symmetric_eigen();
+
+    let mut found: Vec<f64> = values.iter().map(|z| z.re).collect();
+    found.sort_by(|x, y| x.partial_cmp(y).unwrap());
+
+    assert!(values.iter().all(|z| z.im == 0.0));
+    assert!(found
+        .iter()
+        .zip(&expected)
+        .all(|(x, y)| (x - y).abs() < 1e-10));
+}
+
+#[test]
+fn eigenvalues_trace_and_determinant() {
+    let a = general(9);
+
+    let values = a.eigenvalues().unwrap();
+
+    let trace = (0..9).fold(0.0, |acc, i| acc + a.cells[i][i]);
+    let sum = values.iter().fold(0.0, |acc, z| acc + z.re);
+    let imaginary = values.iter().fold(0.0, |acc, z| acc + z.im);
+    assert!((trace - sum).abs() < 1e-9);
+    assert!(imaginary.abs() < 1e-9);
+
+    // product of the eigenvalues as complex numbers
+    let (re, im) = values.iter().fold((1.0, 0.0), |(re, im), z| {
+        (re * z.re - im * z.im, re * z.im + im * z.re)
+    });
+    let det = a.determinant();
+    assert!((re - det).abs() < 1e-6 * det.abs().max(1.0));
+    assert!(im.abs() < 1e-6 * det.abs().max(1.0));
+}
+
+#[test]
+fn eigenvalues_defective() {
+    let a = Matrix::new(vec![
+        vec![2.0, 1.0, 0.0],
+        vec![0.0, 2.0, 1.0],
+        vec![0.0, 0.0, 2.0],
+    ]);
+
+    let values = a.eigenvalues().unwrap();
+
+    assert!(close(&[(2.0, 0.0), (2.0, 0.0), (2.0, 0.0)], &values, 1e-12));
+}
+
+#[test]
+fn no_convergence_display() {
+    assert_eq!(
+        "iteration did not converge",
+        MatrixError::NoConvergence.to_string()
+    );
+}
+
+#[test]
+fn schur_real_pair_is_triangular() {
+    let a = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+
+    let (q, t) = a.schur().unwrap();
+
+    let root = 33f64.sqrt();
+    assert_eq!(0.0, t.cells[1][0]);
+    assert!(((t.cells[0][0] - t.cells[1][1]).abs() - root).abs() < 1e-12);
+    assert!((q.clone() * t * q.transpose()).approx_eq(&a, 1e-12, 0.0));
+}
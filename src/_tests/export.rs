@@ -0,0 +1,84 @@
+use super::*;
+use crate::matrix;
+
+#[test]
+fn latex_environments() {
+    let a = matrix![1, -2; 3, 4];
+
+    assert_eq!(
+        "\\begin{pmatrix}\n1 & -2 \\\\\n3 & 4\n\\end{pmatrix}\n",
+        a.to_latex(LatexEnv::Pmatrix)
+    );
+    assert_eq!(
+        "\\begin{bmatrix}\n1 & -2 \\\\\n3 & 4\n\\end{bmatrix}\n",
+        a.to_latex(LatexEnv::Bmatrix)
+    );
+    assert_eq!(
+        "\\begin{array}{rr}\n1 & -2 \\\\\n3 & 4\n\\end{array}\n",
+        a.to_latex(LatexEnv::Array(Align::Right))
+    );
+    assert!(matrix![1, 2, 3]
+        .to_latex(LatexEnv::Array(Align::Center))
+        .starts_with("\\begin{array}{ccc}\n"));
+}
+
+#[test]
+fn latex_highlight() {
+    let a = matrix![1, 2; 3, 4];
+    let highlight = Highlight {
+        color: "cyan".to_string(),
+        ..Highlight::block(2..=2, 1..=2)
+    };
+
+    assert_eq!(
+        "\\begin{pmatrix}\n1 & 2 \\\\\n\\colorbox{cyan}{$3$} & \\colorbox{cyan}{$4$}\n\\end{pmatrix}\n",
+        a.to_latex_highlighted(LatexEnv::Pmatrix, &highlight)
+    );
+}
+
+#[test]
+fn markdown_table() {
+    let a = matrix![1, 2; 3, 4];
+
+    assert_eq!(
+        "| 1 | 2 |\n|---:|---:|\n| 1 | 2 |\n| 3 | 4 |\n",
+        a.to_markdown_table()
+    );
+    assert_eq!(
+        "| 1 | 2 |\n|---:|---:|\n| 1 | **2** |\n| 3 | 4 |\n",
+        a.to_markdown_table_highlighted(&Highlight::block(1..=1, 2..=2))
+    );
+}
+
+#[test]
+fn html_table() {
+    let a = matrix![1, 2; 3, 4];
+
+    assert_eq!(
+        "<table>\n  <tr><td>1</td><td>2</td></tr>\n  <tr><td>3</td><td>4</td></tr>\n</table>\n",
+        a.to_html_table()
+    );
+    assert_eq!(
+        "<table>\n  <tr><td style=\"background-color: yellow\">1</td><td>2</td></tr>\n  <tr><td>3</td><td>4</td></tr>\n</table>\n",
+        a.to_html_table_highlighted(&Highlight::block(1..=1, 1..=1))
+    );
+}
+
+#[test]
+fn strass_tiles() {
+    assert_eq!(Highlight::block(1..=2, 1..=2), Highlight::tile(1, 1));
+    assert_eq!(Highlight::block(3..=4, 5..=6), Highlight::tile(2, 3));
+
+    // a 3 x 3 matrix is padded to 4 x 4, so the bottom right tile is a single cell
+    let a = Matrix::from_list(3, 3, (1..=9).collect());
+    let table = a.to_markdown_table_highlighted(&Highlight::tile(2, 2));
+
+    assert_eq!("| 7 | 8 | **9** |", table.lines().last().unwrap());
+    assert_eq!("| 4 | 5 | 6 |", table.lines().nth(3).unwrap());
+}
+
+#[test]
+#[should_panic]
+fn tile_from_zero() {
+    Highlight::tile(0, 1);
+}
@@ -0,0 +1,155 @@
+use super::*;
+
+const INF: isize = NO_EDGE;
+
+fn cycle(n: usize) -> Matrix {
+    let mut out = Matrix::zeroes(n, n);
+
+    for i in 0..n {
+        out.cells[i][(i + 1) % n] = 1;
+        out.cells[(i + 1) % n][i] = 1;
+    }
+
+    out
+}
+
+#[test]
+fn walk_counts_triangle() {
+    // closed walks of length 3 in a triangle go round either way
+    let a = cycle(3);
+
+    let walks = a.walk_counts(3);
+
+    assert_eq!(2, walks[[1, 1]]);
+    assert_eq!(3, walks[[1, 2]]);
+}
+
+#[test]
+fn walk_counts_zero_length() {
+    assert_eq!(Matrix::identity(4), cycle(4).walk_counts(0));
+}
+
+#[test]
+fn transitive_closure_chain() {
+    // 1 -> 2 -> 3, 4 on its own
+    let a = Matrix::from_list(4, 4, vec![0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+    let reach = a.transitive_closure();
+
+    assert!(reach[[1, 2]]);
+    assert!(reach[[1, 3]]);
+    assert!(reach[[2, 3]]);
+    assert!(!reach[[3, 1]]);
+    assert!(!reach[[1, 1]]);
+    assert!(!reach[[1, 4]]);
+}
+
+#[test]
+fn transitive_closure_cycle() {
+    let reach = cycle(3).transitive_closure();
+
+    assert_eq!(Matrix::new(vec![vec![true; 3]; 3]), reach);
+}
+
+#[test]
+fn floyd_warshall() {
+    let a = Matrix::new(vec![
+        vec![0, 4, 1, INF],
+        vec![INF, 0, INF, 1],
+        vec![INF, 2, 0, 6],
+        vec![INF, INF, INF, 0],
+    ]);
+
+    let mat = Matrix::new(vec![
+        vec![0, 3, 1, 4],
+        vec![INF, 0, INF, 1],
+        vec![INF, 2, 0, 3],
+        vec![INF, INF, INF, 0],
+    ]);
+
+    assert_eq!(Ok(mat), a.floyd_warshall());
+}
+
+#[test]
+fn floyd_warshall_negative_edge() {
+    let a = Matrix::new(vec![
+        vec![INF, 5, 2],
+        vec![INF, INF, INF],
+        vec![INF, -4, INF],
+    ]);
+
+    let mat = Matrix::new(vec![vec![0, -2, 2], vec![INF, 0, INF], vec![INF, -4, 0]]);
+
+    assert_eq!(Ok(mat), a.floyd_warshall());
+}
+
+#[test]
+fn floyd_warshall_negative_cycle() {
+    let a = Matrix::new(vec![vec![0, 1], vec![-2, 0]]);
+
+    assert_eq!(Err(MatrixError::NegativeCycle), a.floyd_warshall());
+}
+
+#[test]
+fn squaring_matches_floyd_warshall() {
+    let a = Matrix::new(vec![
+        vec![0, 7, INF, INF, 1],
+        vec![INF, 0, 1, INF, INF],
+        vec![INF, INF, 0, 1, INF],
+        vec![2, INF, INF, 0, INF],
+        vec![INF, 1, INF, 9, 0],
+    ]);
+
+    assert_eq!(a.floyd_warshall(), a.shortest_paths_squaring());
+}
+
+#[test]
+fn squaring_negative_cycle() {
+    let a = Matrix::new(vec![vec![0, 1, INF], vec![INF, 0, 1], vec![-3, INF, 0]]);
+
+    assert_eq!(Err(MatrixError::NegativeCycle), a.shortest_paths_squaring());
+}
+
+#[test]
+fn connected_components() {
+    // 1 - 3, 2 -> 4 (directed edges still join a component), 5 alone
+    let mut a = Matrix::zeroes(5, 5);
+    a.cells[0][2] = 1;
+    a.cells[2][0] = 1;
+    a.cells[1][3] = 1;
+
+    assert_eq!(
+        vec![vec![1, 3], vec![2, 4], vec![5]],
+        a.connected_components()
+    );
+}
+
+#[test]
+fn spanning_trees_cycle() {
+    // every edge of a cycle can be the one left out
+    assert_eq!(Ok(5), cycle(5).spanning_tree_count());
+}
+
+#[test]
+fn spanning_trees_complete() {
+    // cayley's formula n^(n-2)
+    let mut k5 = Matrix::from_list(5, 5, vec![1; 25]);
+    for idx in 0..5 {
+        k5.cells[idx][idx] = 0;
+    }
+
+    assert_eq!(Ok(125), k5.spanning_tree_count());
+}
+
+#[test]
+fn spanning_trees_disconnected() {
+    let a = Matrix::from_list(3, 3, vec![0, 1, 0, 1, 0, 0, 0, 0, 0]);
+
+    assert_eq!(Ok(0), a.spanning_tree_count());
+}
+
+#[test]
+#[should_panic]
+fn not_square() {
+    Matrix::from_list(2, 3, vec![0; 6]).transitive_closure();
+}
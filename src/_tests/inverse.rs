@@ -0,0 +1,142 @@
+use super::*;
+
+#[test]
+fn inverse_2x2() {
+    let a = Matrix::from_list(2, 2, vec![1, 2, 3, 4]);
+
+    let inv = a.inverse().unwrap();
+
+    assert_eq!(
+        Matrix::from_list(2, 2, vec![-4, 2, 3, -1]),
+        *inv.numerator()
+    );
+    assert_eq!(2, inv.denominator());
+}
+
+#[test]
+fn inverse_identity_product() {
+    let a = Matrix::from_list(3, 3, vec![2, 3, 6, 5, 1, 4, 3, 2, 1]);
+
+    let inv = a.inverse().unwrap();
+
+    assert_eq!(inv.clone() * a.clone(), Matrix::identity(3));
+    assert_eq!(a * inv, Matrix::identity(3));
+}
+
+#[test]
+fn inverse_needs_pivoting() {
+    let a = Matrix::from_list(3, 3, vec![0, 1, 2, 1, 0, 3, 4, -3, 8]);
+
+    let inv = a.inverse().unwrap();
+
+    assert_eq!(a * inv, Matrix::identity(3));
+}
+
+#[test]
+fn inverse_unimodular_is_integer() {
+    let a = Matrix::from_list(2, 2, vec![2, 1, 1, 1]);
+
+    let inv = a.inverse().unwrap();
+
+    assert_eq!(
+        Some(Matrix::from_list(2, 2, vec![1, -1, -1, 2])),
+        inv.to_matrix()
+    );
+}
+
+#[test]
+fn rational_matrix_overflow() {
+    // isize::MIN / -1 does not fit, the sign must not silently flip
+    let a = Matrix::new(vec![vec![isize::MIN, 2]]);
+
+    assert_eq!(
+        Err(MatrixError::Overflow),
+        RationalMatrix::new(a.clone(), -1)
+    );
+    // a positive denominator never needs negating
+    assert!(RationalMatrix::new(a, 1).is_ok());
+}
+
+#[test]
+fn rational_product_extreme_entries() {
+    // a product only ever divides by the gcd of a positive denominator
+    let a = RationalMatrix::new(Matrix::new(vec![vec![isize::MIN, 2]]), 4).unwrap();
+
+    let product = a * Matrix::identity(2);
+
+    assert_eq!(
+        Matrix::new(vec![vec![isize::MIN / 2, 1]]),
+        *product.numerator()
+    );
+    assert_eq!(2, product.denominator());
+}
+
+#[test]
+fn inverse_singular() {
+    let a = Matrix::from_list(3, 3, (1..=9).collect());
+
+    assert_eq!(Err(MatrixError::Singular), a.inverse());
+}
+
+#[test]
+#[should_panic]
+fn inverse_not_square() {
+    let a = Matrix::from_list(2, 3, (1..=6).collect());
+
+    let _ = a.inverse();
+}
+
+#[test]
+fn inverse_mod_prime() {
+    let a = Matrix::from_list(2, 2, vec![1, 2, 3, 4]);
+
+    let inv = a.inverse_mod(7).unwrap();
+
+    assert_eq!(Matrix::from_list(2, 2, vec![5, 1, 5, 3]), inv);
+}
+
+#[test]
+fn inverse_mod_identity_product() {
+    let a = Matrix::from_list(3, 3, vec![2, 3, 6, 5, 1, 4, 3, 2, 1]);
+
+    let inv = a.inverse_mod(11).unwrap();
+    let mut prod = a * inv;
+
+    for cell in prod.cells.iter_mut().flatten() {
+        *cell = cell.rem_euclid(11);
+    }
+
+    assert_eq!(Matrix::identity(3), prod);
+}
+
+#[test]
+fn inverse_mod_composite() {
+    // det = -5 is a unit mod 6 even though no entry of the first column is
+    let a = Matrix::from_list(2, 2, vec![2, 3, 3, 2]);
+
+    let inv = a.inverse_mod(6).unwrap();
+    let mut prod = a * inv;
+
+    for cell in prod.cells.iter_mut().flatten() {
+        *cell = cell.rem_euclid(6);
+    }
+
+    assert_eq!(Matrix::identity(2), prod);
+}
+
+#[test]
+fn inverse_mod_singular() {
+    // det = -2 is zero mod 2
+    let a = Matrix::from_list(2, 2, vec![1, 2, 3, 4]);
+
+    assert_eq!(Err(MatrixError::Singular), a.inverse_mod(2));
+}
+
+#[test]
+fn inverse_mod_negative_entries() {
+    let a = Matrix::from_list(2, 2, vec![-1, 0, 0, -1]);
+
+    let inv = a.inverse_mod(5).unwrap();
+
+    assert_eq!(Matrix::from_list(2, 2, vec![4, 0, 0, 4]), inv);
+}
@@ -0,0 +1,218 @@
+use super::*;
+
+fn sample(n: usize) -> Matrix<f64> {
+    // diagonally heavy but not symmetric, with a row order that forces pivoting
+    let list = (0..n * n)
+        .map(|idx| {
+            let (r, c) = (idx / n, idx % n);
+            let base = ((r * 7 + c * 13) % 17) as f64 / 17.0 - 0.5;
+            if (r + 1) % n == c {
+                base + n as f64
+            } else {
+                base
+            }
+        })
+        .collect();
+
+    Matrix::from_list(n, n, list)
+}
+
+#[test]
+fn lu_2x2() {
+    let a = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+
+    let lu = a.lu().unwrap();
+
+    assert_eq!(
+        Matrix::new(vec![vec![1.0, 0.0], vec![1.0 / 3.0, 1.0]]),
+        lu.l()
+    );
+    assert_eq!(
+        Matrix::new(vec![vec![3.0, 4.0], vec![0.0, 2.0 - 4.0 / 3.0]]),
+        lu.u()
+    );
+    assert_eq!(&[1, 0], lu.permutation());
+}
+
+#[test]
+fn lu_reconstructs() {
+    let a = Matrix::new(vec![
+        vec![2.0, 1.0, 1.0],
+        vec![4.0, -6.0, 0.0],
+        vec![-2.0, 7.0, 2.0],
+    ]);
+
+    let lu = a.lu().unwrap();
+
+    assert!((lu.p() * a).approx_eq(&(lu.l() * lu.u()), 1e-12, 1e-12));
+}
+
+#[test]
+fn lu_blocked_reconstructs() {
+    // big enough to take the recursive path with a strass trailing update
+    let a = sample(40);
+
+    let lu = a.lu().unwrap();
+
+    assert!((lu.p() * a).approx_eq(&(lu.l() * lu.u()), 1e-10, 1e-10));
+}
+
+#[test]
+fn lu_singular() {
+    // the last pivot is rounding error, lu keeps it but solving refuses it
+    let a = Matrix::from_list(3, 3, (1..=9).map(|x| x as f64).collect());
+    let b = Matrix::new(vec![vec![1.0], vec![2.0], vec![3.0]]);
+
+    assert!(a.lu().is_ok());
+    assert!(a.determinant().abs() < 1e-12);
+    assert_eq!(Err(MatrixError::Singular), a.solve(&b));
+    assert_eq!(Err(MatrixError::Singular), a.inverse());
+}
+
+#[test]
+fn badly_scaled_diagonal() {
+    // rows in very different units are still regular
+    let a: Matrix<f64> = Matrix::new(vec![vec![1e20, 0.0], vec![0.0, 1.0]]);
+    let b: Matrix<f64> = Matrix::new(vec![vec![1e12, 0.0], vec![0.0, 1e-6]]);
+
+    assert_eq!(1e20, a.determinant());
+    assert!((b.determinant() - 1e6).abs() < 1e-6);
+    assert_eq!(
+        Matrix::new(vec![vec![1e-20, 0.0], vec![0.0, 1.0]]),
+        a.inverse().unwrap()
+    );
+    assert_eq!(
+        Matrix::new(vec![vec![1e-12, 0.0], vec![0.0, 1e6]]),
+        b.inverse().unwrap()
+    );
+}
+
+#[test]
+fn badly_scaled_columns() {
+    // the second column is in very different units, the first pivot is not noise
+    let a: Matrix<f64> = Matrix::new(vec![vec![1.0, 1e20], vec![1.0, -1e20]]);
+    let b: Matrix<f64> = Matrix::new(vec![vec![3.0], vec![1.0]]);
+
+    assert_eq!(-2e20, a.determinant());
+    assert!(a.inverse().unwrap().approx_eq(
+        &Matrix::new(vec![vec![0.5, 0.5], vec![0.5e-20, -0.5e-20]]),
+        0.0,
+        1e-12
+    ));
+    assert!(a
+        .solve(&b)
+        .unwrap()
+        .approx_eq(&Matrix::new(vec![vec![2.0], vec![1e-20]]), 0.0, 1e-12));
+}
+
+#[test]
+fn lu_nan() {
+    let a: Matrix<f64> = Matrix::new(vec![vec![f64::NAN, 1.0], vec![0.0, 1.0]]);
+    let b: Matrix<f64> = Matrix::new(vec![vec![0.0, 1.0], vec![f64::NAN, 1.0]]);
+
+    // NaN is not mistaken for a zero pivot, so it reaches the determinant
+    assert!(a.determinant().is_nan());
+    assert!(b.determinant().is_nan());
+    assert_eq!(Err(MatrixError::Singular), a.inverse());
+}
+
+#[test]
+fn lu_solve_checks_pivots() {
+    // the factorisation itself succeeds, solving with it does not
+    let a = Matrix::from_list(3, 3, (1..=9).map(|x| x as f64).collect());
+
+    let lu = a.lu().unwrap();
+
+    assert_eq!(Err(MatrixError::Singular), lu.inverse());
+    assert_eq!(Err(MatrixError::Singular), lu.solve(&Matrix::identity(3)));
+}
+
+#[test]
+fn lu_zero_matrix() {
+    let a: Matrix<f64> = Matrix::zeroes(2, 2);
+
+    assert_eq!(Err(MatrixError::Singular), a.lu());
+}
+
+#[test]
+#[should_panic]
+fn lu_not_square() {
+    let a: Matrix<f64> = Matrix::zeroes(2, 3);
+
+    let _ = a.lu();
+}
+
+#[test]
+fn solve_vector() {
+    let a = Matrix::new(vec![
+        vec![2.0, 1.0, -1.0],
+        vec![-3.0, -1.0, 2.0],
+        vec![-2.0, 1.0, 2.0],
+    ]);
+    let b = Matrix::new(vec![vec![8.0], vec![-11.0], vec![-3.0]]);
+
+    let x = a.solve(&b).unwrap();
+
+    assert!(x.approx_eq(
+        &Matrix::new(vec![vec![2.0], vec![3.0], vec![-1.0]]),
+        1e-12,
+        0.0
+    ));
+}
+
+#[test]
+fn solve_multiple_columns() {
+    let a = sample(20);
+    let x = Matrix::from_list(20, 3, (0..60).map(|x| x as f64 - 30.0).collect());
+    let b = a.clone() * x.clone();
+
+    assert!(a.solve(&b).unwrap().approx_eq(&x, 1e-9, 1e-9));
+}
+
+#[test]
+fn solve_singular() {
+    let a = Matrix::new(vec![vec![1.0, 2.0], vec![2.0, 4.0]]);
+    let b = Matrix::new(vec![vec![1.0], vec![2.0]]);
+
+    assert_eq!(Err(MatrixError::Singular), a.solve(&b));
+}
+
+#[test]
+fn determinant() {
+    let a: Matrix<f64> = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    let b: Matrix<f64> = Matrix::new(vec![vec![1.0, 2.0], vec![2.0, 4.0]]);
+
+    assert!((a.determinant() + 2.0).abs() < 1e-12);
+    assert_eq!(0.0, b.determinant());
+}
+
+#[test]
+fn determinant_matches_integer() {
+    let a = Matrix::from_list(3, 3, vec![2, 3, 6, 5, 1, 4, 3, 2, 1]);
+
+    let det = a.map(|x| x as f64).determinant();
+
+    assert!((det - 49.0).abs() < 1e-10);
+}
+
+#[test]
+fn inverse() {
+    let a = sample(25);
+
+    let inv = a.inverse().unwrap();
+
+    assert!((a * inv).approx_eq(&Matrix::identity(25), 1e-12, 0.0));
+}
+
+#[test]
+fn inverse_f32() {
+    let a = Matrix::new(vec![vec![4.0f32, 7.0], vec![2.0, 6.0]]);
+
+    let inv = a.inverse().unwrap();
+
+    assert!(inv.approx_eq(
+        &Matrix::new(vec![vec![0.6, -0.7], vec![-0.2, 0.4]]),
+        1e-6,
+        0.0
+    ));
+}
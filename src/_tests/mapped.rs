@@ -0,0 +1,148 @@
+use std::fs;
+use std::path::PathBuf;
+
+use super::*;
+use crate::matrix;
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("matrix-rs-{}-{}.mtrx", std::process::id(), name))
+}
+
+fn write_file<T: BinaryElement>(name: &str, a: &Matrix<T>) -> PathBuf {
+    let path = temp_path(name);
+    a.write_to(File::create(&path).unwrap()).unwrap();
+    path
+}
+
+#[test]
+fn open_and_read() {
+    let a = matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    let path = write_file("open", &a);
+
+    let mapped = unsafe { MappedMatrix::<isize>::open(&path) }.unwrap();
+
+    assert_eq!((3, 3), (mapped.rows(), mapped.cols()));
+    assert_eq!(6, mapped.get([2, 3]));
+    assert_eq!(matrix![5, 6; 8, 9], mapped.read_tile(2, 2, 2, 2));
+    assert_eq!(a, mapped.to_matrix());
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn create_and_write() {
+    let path = temp_path("create");
+
+    let mut mapped = unsafe { MappedMatrix::<f64>::create(&path, 2, 3) }.unwrap();
+    mapped.set([1, 1], 1.5);
+    mapped.write_tile(1, 2, &matrix![2.0, 3.0; 4.0, 5.0]);
+    mapped.flush().unwrap();
+
+    let read = Matrix::<f64>::read_from(File::open(&path).unwrap()).unwrap();
+    assert_eq!(matrix![1.5, 2.0, 3.0; 0.0, 4.0, 5.0], read);
+
+    // dropping without a flush still leaves a valid file
+    mapped.set([2, 1], -1.0);
+    drop(mapped);
+    let read = unsafe { MappedMatrix::<f64>::open(&path) }.unwrap();
+    assert_eq!(-1.0, read.get([2, 1]));
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn tiled_multiplication() {
+    let a = Matrix::from_list(5, 3, (1..=15).collect());
+    let b = Matrix::from_list(3, 4, (-6..6).collect());
+    let a_path = write_file("mul-a", &a);
+    let b_path = write_file("mul-b", &b);
+    let a_map = unsafe { MappedMatrix::<isize>::open(&a_path) }.unwrap();
+    let b_map = unsafe { MappedMatrix::<isize>::open(&b_path) }.unwrap();
+    let expected = a.clone() * b.clone();
+
+    for (kernel, tile) in [
+        (Kernel::Blocked, 2),
+        (Kernel::Strassen, 2),
+        (Kernel::Strassen, 3),
+        (Kernel::Blocked, 8),
+    ] {
+        let out_path = temp_path("mul-out");
+        let out = unsafe { a_map.multiply_to(&b_map, &out_path, tile, kernel) }.unwrap();
+
+        assert_eq!(expected, out.to_matrix(), "{:?} with tile {}", kernel, tile);
+        // the result on disk is a complete file in the binary format
+        assert_eq!(
+            expected,
+            Matrix::read_from(File::open(&out_path).unwrap()).unwrap()
+        );
+
+        drop(out);
+        fs::remove_file(out_path).unwrap();
+    }
+
+    fs::remove_file(a_path).unwrap();
+    fs::remove_file(b_path).unwrap();
+}
+
+#[test]
+fn multiply_to_input_path() {
+    let a = matrix![1isize, 2; 3, 4];
+    let a_path = write_file("mul-self", &a);
+    let a_map = unsafe { MappedMatrix::<isize>::open(&a_path) }.unwrap();
+
+    // a different spelling of the same file is still caught
+    let alias = a_path
+        .parent()
+        .unwrap()
+        .join(".")
+        .join(a_path.file_name().unwrap());
+    for path in [&a_path, &alias] {
+        assert!(matches!(
+            unsafe { a_map.multiply_to(&a_map, path, 2, Kernel::Blocked) },
+            Err(BinaryError::Io(err)) if err.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
+
+    // the input was left alone
+    assert_eq!(a, a_map.to_matrix());
+
+    drop(a_map);
+    fs::remove_file(a_path).unwrap();
+}
+
+#[test]
+fn corrupt_files() {
+    let path = write_file("corrupt", &matrix![1isize, 2; 3, 4]);
+    let mut bytes = fs::read(&path).unwrap();
+
+    bytes[HEADER_LEN] ^= 1;
+    fs::write(&path, &bytes).unwrap();
+    assert!(matches!(
+        unsafe { MappedMatrix::<isize>::open(&path) },
+        Err(BinaryError::ChecksumMismatch { .. })
+    ));
+
+    fs::write(&path, &bytes[..bytes.len() - 2]).unwrap();
+    assert!(matches!(
+        unsafe { MappedMatrix::<isize>::open(&path) },
+        Err(BinaryError::Truncated)
+    ));
+
+    fs::write(&path, &bytes[..10]).unwrap();
+    assert!(matches!(
+        unsafe { MappedMatrix::<isize>::open(&path) },
+        Err(BinaryError::Truncated)
+    ));
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn write_read_only() {
+    let path = write_file("read-only", &matrix![1isize, 2]);
+    let mut mapped = unsafe { MappedMatrix::<isize>::open(&path) }.unwrap();
+    fs::remove_file(path).unwrap();
+
+    mapped.set([1, 1], 3);
+}
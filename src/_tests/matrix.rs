@@ -1,4 +1,5 @@
 use super::*;
+use crate::matrix;
 
 #[test]
 fn new() {
@@ -155,7 +156,7 @@ fn from_mat_2x2_1x2() {
 
 #[test]
 fn index_2x2() {
-    let mat = Matrix::new(vec![vec![1, 2], vec![3, 4]]);
+    let mat = matrix![1, 2; 3, 4];
 
     assert_eq!(1, mat[[1, 1]]);
     assert_eq!(2, mat[[1, 2]]);
@@ -165,7 +166,7 @@ fn index_2x2() {
 
 #[test]
 fn index_3x3() {
-    let mat = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+    let mat = matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
 
     assert_eq!(1, mat[[1, 1]]);
     assert_eq!(2, mat[[1, 2]]);
@@ -180,20 +181,20 @@ fn index_3x3() {
 
 #[test]
 fn strass_mul() {
-    let a = Matrix::new(vec![vec![1, 2], vec![3, 4]]);
-    let b = Matrix::new(vec![vec![1, 2], vec![3, 4]]);
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![1, 2; 3, 4];
 
-    let mat = Matrix::new(vec![vec![7, 10], vec![15, 22]]);
+    let mat = matrix![7, 10; 15, 22];
 
     assert_eq!(mat, a.strass(&b));
 }
 
 #[test]
 fn strass_ident_mul() {
-    let a = Matrix::new(vec![vec![1, 2], vec![3, 4]]);
-    let b = Matrix::new(vec![vec![1, 0], vec![0, 1]]);
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![1, 0; 0, 1];
 
-    let mat = Matrix::new(vec![vec![1, 2], vec![3, 4]]);
+    let mat = matrix![1, 2; 3, 4];
 
     assert_eq!(mat, a.strass(&b));
 }
@@ -203,13 +204,7 @@ fn strass_mul_3x3() {
     let a = Matrix::from_list(3, 3, (1..=9).collect());
     let b = Matrix::from_list(3, 3, (1..=9).collect());
 
-    let mat = Matrix::from_list(
-        3,
-        3,
-        vec![
-            30, 36, 42, 66, 81, 96, 102, 126, 150
-        ],
-    );
+    let mat = Matrix::from_list(3, 3, vec![30, 36, 42, 66, 81, 96, 102, 126, 150]);
 
     assert_eq!(mat, a.strass(&b));
 }
@@ -217,11 +212,7 @@ fn strass_mul_3x3() {
 #[test]
 fn strass_ident_mul_3x3() {
     let a = Matrix::from_list(3, 3, (1..=9).collect());
-    let b = Matrix::new(vec![
-        vec![1, 0, 0,],
-        vec![0, 1, 0,],
-        vec![0, 0, 1,],
-    ]);
+    let b = matrix![1, 0, 0; 0, 1, 0; 0, 0, 1];
 
     let mat = Matrix::from_list(3, 3, (1..=9).collect());
 
@@ -247,12 +238,7 @@ fn strass_mul_4x4() {
 #[test]
 fn strass_ident_mul_4x4() {
     let a = Matrix::from_list(4, 4, (1..=16).collect());
-    let b = Matrix::new(vec![
-        vec![1, 0, 0, 0],
-        vec![0, 1, 0, 0],
-        vec![0, 0, 1, 0],
-        vec![0, 0, 0, 1],
-    ]);
+    let b = matrix![1, 0, 0, 0; 0, 1, 0, 0; 0, 0, 1, 0; 0, 0, 0, 1];
 
     let mat = Matrix::from_list(4, 4, (1..=16).collect());
 
@@ -261,17 +247,17 @@ fn strass_ident_mul_4x4() {
 
 #[test]
 fn mul_2x2() {
-    let a = Matrix::new(vec![vec![1, 2], vec![3, 4]]);
-    let b = Matrix::new(vec![vec![1, 2], vec![3, 4]]);
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![1, 2; 3, 4];
 
-    let mat = Matrix::new(vec![vec![7, 10], vec![15, 22]]);
+    let mat = matrix![7, 10; 15, 22];
     assert_eq!(mat, a * b);
 }
 
 #[test]
 fn mul_3x3() {
-    let a = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
-    let b = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+    let a = matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    let b = matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
 
     let mat = Matrix::from_list(3, 3, vec![30, 36, 42, 66, 81, 96, 102, 126, 150]);
     assert_eq!(mat, a * b);
@@ -279,63 +265,82 @@ fn mul_3x3() {
 
 #[test]
 fn asym_1x2_2x1_mul() {
-    let a = Matrix::new(vec![vec![1, 2]]);
-    let b = Matrix::new(vec![vec![3], vec![4]]);
+    let a = matrix![1, 2];
+    let b = matrix![3; 4];
 
-    let mat = Matrix::new(vec![vec![11]]);
+    let mat = matrix![11];
     assert_eq!(mat, a * b);
 }
 
 #[test]
 fn asym_2x1_1x2_mul() {
-    let a = Matrix::new(vec![vec![1], vec![2]]);
-    let b = Matrix::new(vec![vec![3, 4]]);
+    let a = matrix![1; 2];
+    let b = matrix![3, 4];
 
-    let mat = Matrix::new(vec![vec![3, 4], vec![6, 8]]);
+    let mat = matrix![3, 4; 6, 8];
     assert_eq!(mat, a * b);
 }
 
 #[test]
 fn asym_3x1_1x3_mul() {
-    let a = Matrix::new(vec![vec![1], vec![2], vec![3]]);
-    let b = Matrix::new(vec![vec![4, 5, 6]]);
+    let a = matrix![1; 2; 3];
+    let b = matrix![4, 5, 6];
 
-    let mat = Matrix::new(vec![vec![4, 5, 6], vec![8, 10, 12], vec![12, 15, 18]]);
+    let mat = matrix![4, 5, 6; 8, 10, 12; 12, 15, 18];
     assert_eq!(mat, a * b);
 }
 
 #[test]
 fn ident_mul() {
-    let a = Matrix::new(vec![vec![1, 2], vec![3, 4]]);
-    let b = Matrix::new(vec![vec![1, 0], vec![0, 1]]);
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![1, 0; 0, 1];
 
-    let mat = Matrix::new(vec![vec![1, 2], vec![3, 4]]);
+    let mat = matrix![1, 2; 3, 4];
 
     assert_eq!(mat, a * b);
 }
 
 #[test]
 fn mul_assign() {
-    let mut a = Matrix::new(vec![vec![1, 2], vec![3, 4]]);
-    let b = Matrix::new(vec![vec![1, 2], vec![3, 4]]);
+    let mut a = matrix![1, 2; 3, 4];
+    let b = matrix![1, 2; 3, 4];
     a *= b;
 
-    let mat = Matrix::new(vec![vec![7, 10], vec![15, 22]]);
+    let mat = matrix![7, 10; 15, 22];
 
     assert_eq!(mat, a);
 }
 
 #[test]
 fn indent_mul_assign() {
-    let mut a = Matrix::new(vec![vec![1, 2], vec![3, 4]]);
-    let b = Matrix::new(vec![vec![1, 0], vec![0, 1]]);
+    let mut a = matrix![1, 2; 3, 4];
+    let b = matrix![1, 0; 0, 1];
     a *= b;
 
-    let mat = Matrix::new(vec![vec![1, 2], vec![3, 4]]);
+    let mat = matrix![1, 2; 3, 4];
 
     assert_eq!(mat, a);
 }
 
+#[test]
+fn add_2x3() {
+    let a = Matrix::from_list(2, 3, (1..=6).collect());
+    let b = Matrix::from_list(2, 3, (1..=6).collect());
+
+    let mat = Matrix::from_list(2, 3, vec![2, 4, 6, 8, 10, 12]);
+
+    assert_eq!(mat, a + b);
+}
+
+#[test]
+#[should_panic]
+fn add_2x3_3x2() {
+    let a = Matrix::from_list(2, 3, (1..=6).collect());
+    let b = Matrix::from_list(3, 2, (1..=6).collect());
+
+    let _ = a + b;
+}
+
 #[test]
 fn block_from_mat_4x4() {
     let mat = Matrix::from_list(4, 4, (1..=16).collect());
@@ -411,3 +416,37 @@ fn block_strass_4x4() {
 
     assert_eq!(block, a.strass(&b))
 }
+
+#[test]
+fn pow_zero() {
+    let a = Matrix::from_list(2, 2, vec![1, 2, 3, 4]);
+
+    assert_eq!(Matrix::identity(2), a.pow(0));
+}
+
+#[test]
+fn pow_3() {
+    let a = Matrix::from_list(2, 2, vec![1, 2, 3, 4]);
+
+    let mat = Matrix::from_list(2, 2, vec![37, 54, 81, 118]);
+
+    assert_eq!(mat, a.pow(3));
+}
+
+#[test]
+fn pow_fibonacci() {
+    let a = Matrix::from_list(2, 2, vec![1, 1, 1, 0]);
+
+    let mat = Matrix::from_list(2, 2, vec![89, 55, 55, 34]);
+
+    assert_eq!(mat, a.pow(10));
+}
+
+#[test]
+fn transpose_2x3() {
+    let a = Matrix::from_list(2, 3, (1..=6).collect());
+
+    let mat = Matrix::from_list(3, 2, vec![1, 4, 2, 5, 3, 6]);
+
+    assert_eq!(mat, a.transpose());
+}
@@ -0,0 +1,285 @@
+use super::*;
+
+fn parse_error<T: MarketElement>(text: &str) -> (usize, usize) {
+    match Matrix::<T>::from_matrix_market(text.as_bytes()) {
+        Err(ReadError::Parse { line, column, .. }) => (line, column),
+        other => panic!("expected a parse error, found {:?}", other),
+    }
+}
+
+#[test]
+fn read_coordinate_integer() {
+    let text = "%%MatrixMarket matrix coordinate integer general
+% a comment
+2 3 3
+1 1 5
+2 3 -7
+
+1 2 1
+";
+
+    let a = Matrix::<isize>::from_matrix_market(text.as_bytes()).unwrap();
+
+    assert_eq!(Matrix::new(vec![vec![5, 1, 0], vec![0, 0, -7]]), a);
+}
+
+#[test]
+fn read_coordinate_real_symmetric() {
+    let text = "%%MatrixMarket matrix coordinate real symmetric
+3 3 4
+1 1 2.5
+2 1 -1e-1
+3 2 4
+3 3 1.0
+";
+
+    let a = Matrix::<f64>::from_matrix_market(text.as_bytes()).unwrap();
+
+    assert_eq!(
+        Matrix::new(vec![
+            vec![2.5, -0.1, 0.0],
+            vec![-0.1, 0.0, 4.0],
+            vec![0.0, 4.0, 1.0],
+        ]),
+        a
+    );
+}
+
+#[test]
+fn read_pattern() {
+    let text = "%%MatrixMarket matrix coordinate pattern general
+2 2 2
+1 2
+2 1
+";
+
+    let a = Matrix::<isize>::from_matrix_market(text.as_bytes()).unwrap();
+
+    assert_eq!(Matrix::new(vec![vec![0, 1], vec![1, 0]]), a);
+}
+
+#[test]
+fn read_array_general_and_symmetric() {
+    let general = "%%MatrixMarket matrix array integer general
+2 2
+1
+3
+2
+4
+";
+    let symmetric = "%%MatrixMarket matrix array real symmetric
+2 2
+1
+2
+3
+";
+
+    assert_eq!(
+        Matrix::new(vec![vec![1, 2], vec![3, 4]]),
+        Matrix::<isize>::from_matrix_market(general.as_bytes()).unwrap()
+    );
+    assert_eq!(
+        Matrix::new(vec![vec![1.0, 2.0], vec![2.0, 3.0]]),
+        Matrix::<f64>::from_matrix_market(symmetric.as_bytes()).unwrap()
+    );
+}
+
+#[test]
+fn integer_file_loads_as_float() {
+    let text = "%%MatrixMarket matrix coordinate integer general\n1 1 1\n1 1 3\n";
+
+    assert_eq!(
+        Matrix::new(vec![vec![3.0]]),
+        Matrix::<f64>::from_matrix_market(text.as_bytes()).unwrap()
+    );
+}
+
+#[test]
+fn read_errors_report_position() {
+    // real value into an integer matrix
+    assert_eq!(
+        (3, 5),
+        parse_error::<isize>("%%MatrixMarket matrix coordinate real general\n1 1 1\n1 1 2.5\n")
+    );
+    // index out of range
+    assert_eq!(
+        (3, 3),
+        parse_error::<isize>("%%MatrixMarket matrix coordinate integer general\n2 2 1\n1 3 4\n")
+    );
+    // too few entries
+    assert_eq!(
+        (4, 1),
+        parse_error::<isize>("%%MatrixMarket matrix coordinate integer general\n2 2 2\n1 1 4\n")
+    );
+    // too many entries
+    assert_eq!(
+        (4, 1),
+        parse_error::<isize>("%%MatrixMarket matrix array integer general\n1 1\n4\n5\n")
+    );
+    // unsupported field
+    assert_eq!(
+        (1, 34),
+        parse_error::<f64>("%%MatrixMarket matrix coordinate complex general\n1 1 1\n1 1 1 0\n")
+    );
+    // missing header
+    assert_eq!((1, 1), parse_error::<isize>("1 1 1\n1 1 1\n"));
+    // upper triangle entry in a symmetric file
+    assert_eq!(
+        (3, 1),
+        parse_error::<isize>("%%MatrixMarket matrix coordinate integer symmetric\n2 2 1\n1 2 4\n")
+    );
+}
+
+#[test]
+fn read_impossible_size() {
+    // refused before anything is allocated rather than aborting
+    let text = "%%MatrixMarket matrix coordinate integer general\n4294967296 4294967296 0\n";
+
+    match Matrix::<isize>::from_matrix_market(text.as_bytes()) {
+        Err(ReadError::Parse { line, message, .. }) => {
+            assert_eq!(2, line);
+            assert_eq!(
+                format!(
+                    "a 4294967296 x 4294967296 matrix is over the limit of {} cells to hold densely",
+                    DEFAULT_DENSE_LIMIT
+                ),
+                message
+            );
+        }
+        other => panic!("expected a parse error, found {:?}", other),
+    }
+}
+
+#[test]
+fn read_dense_limit() {
+    let text = "%%MatrixMarket matrix coordinate integer general\n2 3 1\n1 1 5\n";
+
+    assert!(Matrix::<isize>::from_matrix_market_with_limit(text.as_bytes(), 6).is_ok());
+    assert!(matches!(
+        Matrix::<isize>::from_matrix_market_with_limit(text.as_bytes(), 5),
+        Err(ReadError::Parse { line: 2, .. })
+    ));
+}
+
+#[test]
+fn read_sparse() {
+    // far too large to hold densely, but only one entry is stored
+    let text = "%%MatrixMarket matrix coordinate real general\n1000000 1000000 1\n999999 3 2.5\n";
+
+    let a = CsrMatrix::<f64>::from_matrix_market(text.as_bytes()).unwrap();
+
+    assert_eq!((1000000, 1000000, 1), (a.rows(), a.cols(), a.nnz()));
+    assert_eq!(2.5, a.get([999999, 3]));
+    assert_eq!(0.0, a.get([3, 999999]));
+    assert!(Matrix::<f64>::from_matrix_market(text.as_bytes()).is_err());
+}
+
+#[test]
+fn read_sparse_matches_dense() {
+    let symmetric = "%%MatrixMarket matrix coordinate real symmetric
+3 3 4
+1 1 2.5
+2 1 -1e-1
+3 2 4
+3 3 1.0
+";
+    let array = "%%MatrixMarket matrix array real symmetric\n2 2\n1\n2\n3\n";
+    let pattern = "%%MatrixMarket matrix coordinate pattern general\n2 2 2\n1 2\n2 1\n";
+
+    for text in [symmetric, array, pattern] {
+        assert_eq!(
+            Matrix::<f64>::from_matrix_market(text.as_bytes()).unwrap(),
+            CsrMatrix::<f64>::from_matrix_market(text.as_bytes())
+                .unwrap()
+                .to_matrix()
+        );
+    }
+
+    // the same errors as the dense reader
+    assert!(matches!(
+        CsrMatrix::<isize>::from_matrix_market(
+            "%%MatrixMarket matrix coordinate integer general\n2 2 1\n1 3 4\n".as_bytes()
+        ),
+        Err(ReadError::Parse {
+            line: 3,
+            column: 3,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn read_io_error() {
+    struct Failing;
+
+    impl Read for Failing {
+        fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::other("disk on fire"))
+        }
+    }
+
+    assert!(matches!(
+        Matrix::<isize>::from_matrix_market(Failing),
+        Err(ReadError::Io(_))
+    ));
+}
+
+#[test]
+fn write_coordinate() {
+    let a = Matrix::new(vec![vec![5, 1, 0], vec![0, 0, -7]]);
+    let mut out = Vec::new();
+
+    a.to_matrix_market(&mut out, MarketFormat::Coordinate)
+        .unwrap();
+
+    assert_eq!(
+        "%%MatrixMarket matrix coordinate integer general
+2 3 3
+1 1 5
+1 2 1
+2 3 -7
+",
+        String::from_utf8(out).unwrap()
+    );
+}
+
+#[test]
+fn write_array_symmetric() {
+    let a = Matrix::new(vec![vec![1.5, 2.0], vec![2.0, 3.0]]);
+    let mut out = Vec::new();
+
+    a.to_matrix_market(&mut out, MarketFormat::Array).unwrap();
+
+    assert_eq!(
+        "%%MatrixMarket matrix array real symmetric\n2 2\n1.5\n2\n3\n",
+        String::from_utf8(out).unwrap()
+    );
+}
+
+#[test]
+fn round_trip() {
+    let a = Matrix::from_list(3, 4, vec![0, 2, 0, -1, 7, 0, 0, 0, 3, 3, 0, 9]);
+    let b = Matrix::new(vec![
+        vec![4.0, -0.25, 0.0],
+        vec![-0.25, 1e-3, 2.0],
+        vec![0.0, 2.0, 6.5],
+    ]);
+
+    for format in [MarketFormat::Coordinate, MarketFormat::Array] {
+        let mut out = Vec::new();
+        a.to_matrix_market(&mut out, format).unwrap();
+        assert_eq!(a, Matrix::from_matrix_market(out.as_slice()).unwrap());
+
+        let mut out = Vec::new();
+        b.to_matrix_market(&mut out, format).unwrap();
+        assert_eq!(b, Matrix::from_matrix_market(out.as_slice()).unwrap());
+    }
+}
+
+#[test]
+fn read_error_display() {
+    assert_eq!(
+        "line 3, column 5: invalid integer `2.5`",
+        ReadError::parse(3, 5, "invalid integer `2.5`").to_string()
+    );
+}
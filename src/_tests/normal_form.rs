@@ -0,0 +1,112 @@
+use super::*;
+
+#[test]
+fn hermite_3x3() {
+    let a = Matrix::from_list(3, 3, vec![2, 3, 6, 5, 1, 4, 3, 2, 1]);
+
+    let (h, u) = a.hermite_normal_form().unwrap();
+
+    let mat = Matrix::from_list(3, 3, vec![1, 0, 8, 0, 1, 13, 0, 0, 49]);
+
+    assert_eq!(mat, h);
+    assert_eq!(h, u * a);
+}
+
+#[test]
+fn hermite_reduces_above_pivot() {
+    let a = Matrix::from_list(2, 2, vec![2, 5, 0, 3]);
+
+    let (h, u) = a.hermite_normal_form().unwrap();
+
+    let mat = Matrix::from_list(2, 2, vec![2, 2, 0, 3]);
+
+    assert_eq!(mat, h);
+    assert_eq!(h, u * a);
+}
+
+#[test]
+fn hermite_rank_deficient() {
+    let a = Matrix::from_list(3, 2, vec![2, 4, 3, 6, 5, 10]);
+
+    let (h, u) = a.hermite_normal_form().unwrap();
+
+    let mat = Matrix::from_list(3, 2, vec![1, 2, 0, 0, 0, 0]);
+
+    assert_eq!(mat, h);
+    assert_eq!(h, u * a);
+}
+
+#[test]
+fn hermite_negative_pivot() {
+    let a = Matrix::from_list(2, 2, vec![-3, 1, 0, -2]);
+
+    let (h, u) = a.hermite_normal_form().unwrap();
+
+    let mat = Matrix::from_list(2, 2, vec![3, 1, 0, 2]);
+
+    assert_eq!(mat, h);
+    assert_eq!(h, u * a);
+}
+
+#[test]
+fn smith_2x2() {
+    let a = Matrix::from_list(2, 2, vec![2, 4, 6, 8]);
+
+    let (s, u, v) = a.smith_normal_form().unwrap();
+
+    let mat = Matrix::from_list(2, 2, vec![2, 0, 0, 4]);
+
+    assert_eq!(mat, s);
+    assert_eq!(s, u * a * v);
+}
+
+#[test]
+fn smith_3x3() {
+    let a = Matrix::from_list(3, 3, vec![2, 4, 4, -6, 6, 12, 10, -4, -16]);
+
+    let (s, u, v) = a.smith_normal_form().unwrap();
+
+    let mat = Matrix::from_list(3, 3, vec![2, 0, 0, 0, 6, 0, 0, 0, 12]);
+
+    assert_eq!(mat, s);
+    assert_eq!(s, u * a * v);
+}
+
+#[test]
+fn smith_divisibility_fixup() {
+    // diag(2, 3) is not in smith form, the invariant factors are 1 and 6
+    let a = Matrix::from_list(2, 2, vec![2, 0, 0, 3]);
+
+    let (s, u, v) = a.smith_normal_form().unwrap();
+
+    let mat = Matrix::from_list(2, 2, vec![1, 0, 0, 6]);
+
+    assert_eq!(mat, s);
+    assert_eq!(s, u * a * v);
+}
+
+#[test]
+fn smith_rectangular() {
+    let a = Matrix::from_list(2, 3, vec![1, 2, 3, 4, 5, 6]);
+
+    let (s, u, v) = a.smith_normal_form().unwrap();
+
+    let mat = Matrix::from_list(2, 3, vec![1, 0, 0, 0, 3, 0]);
+
+    assert_eq!(mat, s);
+    assert_eq!(s, u * a * v);
+}
+
+#[test]
+fn hermite_overflow() {
+    let a = Matrix::from_list(2, 2, vec![isize::MIN, 1, 0, 1]);
+
+    assert_eq!(Err(MatrixError::Overflow), a.hermite_normal_form());
+}
+
+#[test]
+fn smith_overflow() {
+    let a = Matrix::from_list(2, 2, vec![isize::MAX, 1, isize::MAX - 1, isize::MIN]);
+
+    assert_eq!(Err(MatrixError::Overflow), a.smith_normal_form());
+}
@@ -0,0 +1,319 @@
+use std::io::Cursor;
+
+use super::*;
+
+fn npy_bytes(version: u8, header: &str, data: &[u8]) -> Vec<u8> {
+    let mut out = NPY_MAGIC.to_vec();
+    out.extend_from_slice(&[version, 0]);
+    if version == 1 {
+        out.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    } else {
+        out.extend_from_slice(&(header.len() as u32).to_le_bytes());
+    }
+    out.extend_from_slice(header.as_bytes());
+    out.extend_from_slice(data);
+    out
+}
+
+fn zip_bytes(entries: &[(&str, u16, Vec<u8>, usize)]) -> Vec<u8> {
+    // minimal archive, the crc is left as zero since it is not read
+    let mut out = Vec::new();
+    let mut directory = Vec::new();
+
+    for (name, method, data, uncompressed) in entries {
+        let offset = out.len() as u32;
+
+        out.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        out.extend_from_slice(&[20, 0, 0, 0]);
+        out.extend_from_slice(&method.to_le_bytes());
+        out.extend_from_slice(&[0; 16]);
+        out.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        out.extend_from_slice(&0u16.to_le_bytes());
+        out.extend_from_slice(name.as_bytes());
+        out.extend_from_slice(data);
+
+        directory.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        directory.extend_from_slice(&[20, 0, 20, 0, 0, 0]);
+        directory.extend_from_slice(&method.to_le_bytes());
+        directory.extend_from_slice(&[0; 8]);
+        directory.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        directory.extend_from_slice(&(*uncompressed as u32).to_le_bytes());
+        directory.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        directory.extend_from_slice(&[0; 12]);
+        directory.extend_from_slice(&offset.to_le_bytes());
+        directory.extend_from_slice(name.as_bytes());
+    }
+
+    let directory_offset = out.len() as u32;
+    out.extend_from_slice(&directory);
+    out.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+    out.extend_from_slice(&[0; 4]);
+    out.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+    out.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+    out.extend_from_slice(&(directory.len() as u32).to_le_bytes());
+    out.extend_from_slice(&directory_offset.to_le_bytes());
+    out.extend_from_slice(&[0; 2]);
+    out
+}
+
+fn format_message<T: NpyElement>(bytes: &[u8]) -> String {
+    match Matrix::<T>::read_npy(bytes) {
+        Err(ReadError::Format(message)) => message,
+        other => panic!("expected a format error, found {:?}", other),
+    }
+}
+
+#[test]
+fn read_little_endian_int64() {
+    let data: Vec<u8> = [1i64, -2, 3, 4, 5, -6]
+        .iter()
+        .flat_map(|x| x.to_le_bytes())
+        .collect();
+    let bytes = npy_bytes(
+        1,
+        "{'descr': '<i8', 'fortran_order': False, 'shape': (2, 3), }\n",
+        &data,
+    );
+
+    let a = Matrix::<isize>::read_npy(bytes.as_slice()).unwrap();
+
+    assert_eq!(Matrix::new(vec![vec![1, -2, 3], vec![4, 5, -6]]), a);
+}
+
+#[test]
+fn read_big_endian_fortran_float32() {
+    // column major, so the stored order is 1, 4, 2, 5, 3, 6
+    let data: Vec<u8> = [1.0f32, 4.0, 2.0, 5.0, 3.0, 6.5]
+        .iter()
+        .flat_map(|x| x.to_be_bytes())
+        .collect();
+    let bytes = npy_bytes(
+        2,
+        "{'descr': '>f4', 'fortran_order': True, 'shape': (2, 3), }\n",
+        &data,
+    );
+
+    let a = Matrix::<f64>::read_npy(bytes.as_slice()).unwrap();
+
+    assert_eq!(
+        Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.5]]),
+        a
+    );
+}
+
+#[test]
+fn read_int32_into_float_and_int64() {
+    let data: Vec<u8> = [7i32, -8].iter().flat_map(|x| x.to_le_bytes()).collect();
+    let bytes = npy_bytes(
+        1,
+        "{'descr': '<i4', 'fortran_order': False, 'shape': (1, 2), }\n",
+        &data,
+    );
+
+    assert_eq!(
+        Matrix::new(vec![vec![7.0, -8.0]]),
+        Matrix::<f32>::read_npy(bytes.as_slice()).unwrap()
+    );
+    assert_eq!(
+        Matrix::new(vec![vec![7i64, -8]]),
+        Matrix::<i64>::read_npy(bytes.as_slice()).unwrap()
+    );
+}
+
+#[test]
+fn read_errors() {
+    let float = npy_bytes(
+        1,
+        "{'descr': '<f8', 'fortran_order': False, 'shape': (1, 1), }\n",
+        &1.5f64.to_le_bytes(),
+    );
+    assert_eq!(
+        "Float64 value does not fit the element type",
+        format_message::<i32>(&float)
+    );
+
+    let large = npy_bytes(
+        1,
+        "{'descr': '<i8', 'fortran_order': False, 'shape': (1, 1), }\n",
+        &(1i64 << 40).to_le_bytes(),
+    );
+    assert_eq!(
+        "Int64 value does not fit the element type",
+        format_message::<i32>(&large)
+    );
+
+    let vector = npy_bytes(
+        1,
+        "{'descr': '<i8', 'fortran_order': False, 'shape': (3,), }\n",
+        &[0; 24],
+    );
+    assert_eq!(
+        "expected a 2-D array, found shape (3,)",
+        format_message::<i64>(&vector)
+    );
+
+    let complex = npy_bytes(
+        1,
+        "{'descr': '<c16', 'fortran_order': False, 'shape': (1, 1), }\n",
+        &[0; 16],
+    );
+    assert_eq!("unsupported dtype `<c16`", format_message::<f64>(&complex));
+
+    // a multi byte first character is not split in the middle
+    let accented = npy_bytes(
+        1,
+        "{'descr': 'éi8', 'fortran_order': False, 'shape': (1, 1), }\n",
+        &[0; 8],
+    );
+    assert_eq!("unsupported dtype `éi8`", format_message::<i64>(&accented));
+
+    assert_eq!(
+        "missing NUMPY magic string",
+        format_message::<f64>(b"PK\x03\x04 not an npy")
+    );
+
+    let truncated = npy_bytes(
+        1,
+        "{'descr': '<i8', 'fortran_order': False, 'shape': (2, 2), }\n",
+        &[0; 24],
+    );
+    assert!(matches!(
+        Matrix::<i64>::read_npy(truncated.as_slice()),
+        Err(ReadError::Io(_))
+    ));
+}
+
+#[test]
+fn write_npy_layout() {
+    let a = Matrix::new(vec![vec![1i32, 2], vec![3, 4]]);
+    let mut out = Vec::new();
+
+    a.write_npy(&mut out).unwrap();
+
+    let header_len = u16::from_le_bytes([out[8], out[9]]) as usize;
+    let header = std::str::from_utf8(&out[10..10 + header_len]).unwrap();
+    assert_eq!(0, (10 + header_len) % 64);
+    assert!(header.starts_with("{'descr': '<i4', 'fortran_order': False, 'shape': (2, 2), }"));
+    assert!(header.ends_with('\n'));
+    assert_eq!(10 + header_len + 16, out.len());
+}
+
+#[test]
+fn npy_round_trip() {
+    let a = Matrix::new(vec![vec![0.5, -1e-300, 2.0], vec![3.25, 0.0, 1e300]]);
+    let b = Matrix::new(vec![vec![isize::MIN, 0], vec![1, isize::MAX]]);
+    let c = Matrix::new(vec![vec![1.5f32, -2.25]]);
+
+    let mut out = Vec::new();
+    a.write_npy(&mut out).unwrap();
+    assert_eq!(a, Matrix::read_npy(out.as_slice()).unwrap());
+
+    let mut out = Vec::new();
+    b.write_npy(&mut out).unwrap();
+    assert_eq!(b, Matrix::read_npy(out.as_slice()).unwrap());
+
+    let mut out = Vec::new();
+    c.write_npy(&mut out).unwrap();
+    assert_eq!(c, Matrix::read_npy(out.as_slice()).unwrap());
+}
+
+#[test]
+fn read_npz_stored_and_deflated() {
+    let a = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    let b = Matrix::new(vec![vec![-0.5, 0.25, 8.0]]);
+
+    let mut a_npy = Vec::new();
+    a.write_npy(&mut a_npy).unwrap();
+    let mut b_npy = Vec::new();
+    b.write_npy(&mut b_npy).unwrap();
+    let b_deflated = miniz_oxide::deflate::compress_to_vec(&b_npy, 6);
+
+    let archive = zip_bytes(&[
+        ("weights.npy", 0, a_npy.clone(), a_npy.len()),
+        ("bias.npy", 8, b_deflated, b_npy.len()),
+    ]);
+
+    let matrices = read_npz::<f64, _>(Cursor::new(archive)).unwrap();
+
+    assert_eq!(2, matrices.len());
+    assert_eq!(a, matrices["weights"]);
+    assert_eq!(b, matrices["bias"]);
+}
+
+#[test]
+fn read_npz_errors() {
+    assert!(matches!(
+        read_npz::<f64, _>(Cursor::new(b"not a zip archive at all".to_vec())),
+        Err(ReadError::Format(_))
+    ));
+
+    let archive = zip_bytes(&[("x.npy", 12, vec![0; 4], 4)]);
+    match read_npz::<f64, _>(Cursor::new(archive)) {
+        Err(ReadError::Format(message)) => {
+            assert_eq!("unsupported zip compression method 12 in `x.npy`", message)
+        }
+        other => panic!("expected a format error, found {:?}", other),
+    }
+}
+
+#[test]
+fn read_npz_bounded_inflate() {
+    // 4 MiB of zeros deflate to a few KiB, inflating stops at the directory's size
+    let bomb = miniz_oxide::deflate::compress_to_vec(&vec![0; 4 << 20], 6);
+    let archive = zip_bytes(&[("bomb.npy", 8, bomb, 128)]);
+    assert!(matches!(
+        read_npz::<f64, _>(Cursor::new(archive)),
+        Err(ReadError::Format(_))
+    ));
+
+    // a member shorter than its directory entry is refused too
+    let mut a_npy = Vec::new();
+    Matrix::new(vec![vec![1.0]]).write_npy(&mut a_npy).unwrap();
+    let archive = zip_bytes(&[("a.npy", 0, a_npy.clone(), a_npy.len() + 1)]);
+    match read_npz::<f64, _>(Cursor::new(archive)) {
+        Err(ReadError::Format(message)) => assert_eq!(
+            format!(
+                "`a.npy` holds {} bytes but the zip directory gives {}",
+                a_npy.len(),
+                a_npy.len() + 1
+            ),
+            message
+        ),
+        other => panic!("expected a format error, found {:?}", other),
+    }
+}
+
+#[test]
+fn read_hostile_sizes() {
+    // a shape whose byte count overflows is refused before anything is allocated
+    let overflow = npy_bytes(
+        1,
+        "{'descr': '<f8', 'fortran_order': False, 'shape': (4611686018427387904, 8), }\n",
+        &[0; 8],
+    );
+    assert_eq!(
+        "npy shape (4611686018427387904, 8) is too large",
+        format_message::<f64>(&overflow)
+    );
+
+    // a huge but representable shape runs out of data
+    let huge = npy_bytes(
+        1,
+        "{'descr': '<f8', 'fortran_order': False, 'shape': (1000000000, 1000), }\n",
+        &[0; 8],
+    );
+    assert!(matches!(
+        Matrix::<f64>::read_npy(huge.as_slice()),
+        Err(ReadError::Io(_))
+    ));
+
+    // so does a header length of 4 GiB
+    let mut long_header = NPY_MAGIC.to_vec();
+    long_header.extend_from_slice(&[2, 0]);
+    long_header.extend_from_slice(&u32::MAX.to_le_bytes());
+    long_header.extend_from_slice(b"{'descr': '<f8'");
+    assert!(matches!(
+        Matrix::<f64>::read_npy(long_header.as_slice()),
+        Err(ReadError::Io(_))
+    ));
+}
@@ -0,0 +1,92 @@
+use super::*;
+
+fn parse_error(text: &str) -> (usize, String) {
+    let err = text.parse::<Matrix>().unwrap_err();
+    (err.position, err.message)
+}
+
+#[test]
+fn parse_matlab_style() {
+    let a: Matrix = "[1 2 3; 4 5 6]".parse().unwrap();
+
+    assert_eq!(matrix![1, 2, 3; 4, 5, 6], a);
+}
+
+#[test]
+fn parse_comma_separated() {
+    let a: Matrix = "1,2;3,4".parse().unwrap();
+
+    assert_eq!(matrix![1, 2; 3, 4], a);
+}
+
+#[test]
+fn parse_mixed_separators_and_lines() {
+    let a: Matrix = "  [ 1, -2  3 ;\n   4 ,5, 6;\n]  ".parse().unwrap();
+
+    assert_eq!(matrix![1, -2, 3; 4, 5, 6], a);
+}
+
+#[test]
+fn parse_floats() {
+    let a: Matrix<f64> = "[0.5 -1e3; 2.25 0]".parse().unwrap();
+
+    assert_eq!(matrix![0.5, -1000.0; 2.25, 0.0], a);
+}
+
+#[test]
+fn parse_single_element() {
+    let a: Matrix = "7".parse().unwrap();
+
+    assert_eq!(matrix![7], a);
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(
+        (8, "row 2 has 1 elements, expected 2".to_string()),
+        parse_error("[1 2;  3]")
+    );
+    assert_eq!(
+        (4, "invalid element `x`".to_string()),
+        parse_error("[1 x; 3 4]")
+    );
+    assert_eq!(
+        (3, "expected an element before `,`".to_string()),
+        parse_error("1,,2")
+    );
+    assert_eq!((2, "trailing `,`".to_string()), parse_error("1,;2,"));
+    assert_eq!((5, "missing closing `]`".to_string()), parse_error("[1 2"));
+    assert_eq!((4, "unexpected `]`".to_string()), parse_error("1 2]"));
+    assert_eq!((2, "unexpected `[`".to_string()), parse_error("[[1 2]]"));
+    assert_eq!((1, "empty matrix".to_string()), parse_error("   "));
+    assert_eq!((2, "empty matrix".to_string()), parse_error("[ ; ]"));
+}
+
+#[test]
+fn parse_error_display() {
+    let err = "[1 2; 3]".parse::<Matrix>().unwrap_err();
+
+    assert_eq!(
+        "position 7: row 2 has 1 elements, expected 2",
+        err.to_string()
+    );
+}
+
+#[test]
+fn macro_matches_new() {
+    let a = matrix![1.5, 2.0; -3.0, 4.0; 0.0, 1.0;];
+
+    assert_eq!(
+        Matrix::new(vec![vec![1.5, 2.0], vec![-3.0, 4.0], vec![0.0, 1.0]]),
+        a
+    );
+}
+
+#[test]
+fn macro_evaluates_expressions() {
+    let x = 3;
+
+    let a = matrix![x + 1, x * 2];
+
+    assert_eq!(Matrix::new(vec![vec![4, 6]]), a);
+}
@@ -0,0 +1,205 @@
+use super::*;
+use crate::matrix;
+
+fn format_message<T: std::fmt::Debug>(result: Result<T, ReadError>) -> String {
+    match result {
+        Err(ReadError::Format(message)) => message,
+        other => panic!("expected a format error, found {:?}", other),
+    }
+}
+
+#[test]
+fn read_ascii_pgm() {
+    let text = "P2\n# made by hand\n3 2\n# comment between fields\n15\n0 3 6\n9 12 15\n";
+
+    let a = Matrix::read_pgm(text.as_bytes()).unwrap();
+
+    assert_eq!(matrix![0, 3, 6; 9, 12, 15], a);
+}
+
+#[test]
+fn read_binary_pgm() {
+    let mut bytes = b"P5 2 2 255\n".to_vec();
+    bytes.extend_from_slice(&[0, 10, 200, 255]);
+    assert_eq!(
+        matrix![0, 10; 200, 255],
+        Matrix::read_pgm(bytes.as_slice()).unwrap()
+    );
+
+    // two big endian bytes per sample above 255
+    let mut bytes = b"P5\n2 1\n1000\n".to_vec();
+    bytes.extend_from_slice(&[0x03, 0xe8, 0x00, 0x07]);
+    assert_eq!(
+        matrix![1000, 7],
+        Matrix::read_pgm(bytes.as_slice()).unwrap()
+    );
+}
+
+#[test]
+fn pgm_round_trip() {
+    let a = matrix![0, 1, 2, 3; 250, 251, 254, 255];
+
+    for encoding in [PnmEncoding::Ascii, PnmEncoding::Binary] {
+        let options = PnmOptions {
+            encoding,
+            ..PnmOptions::default()
+        };
+        let mut out = Vec::new();
+        a.write_pgm(&mut out, &options).unwrap();
+
+        assert_eq!(a, Matrix::read_pgm(out.as_slice()).unwrap());
+    }
+
+    let wide = matrix![0, 40000, 65535];
+    let mut out = Vec::new();
+    let options = PnmOptions {
+        maxval: 65535,
+        ..PnmOptions::default()
+    };
+    wide.write_pgm(&mut out, &options).unwrap();
+    assert_eq!(wide, Matrix::read_pgm(out.as_slice()).unwrap());
+}
+
+#[test]
+fn write_ascii_layout() {
+    let mut out = Vec::new();
+    let options = PnmOptions {
+        encoding: PnmEncoding::Ascii,
+        maxval: 9,
+        rescale: false,
+    };
+    matrix![1, 2; 3, 4].write_pgm(&mut out, &options).unwrap();
+    assert_eq!("P2\n2 2\n9\n1 2 3 4\n", String::from_utf8(out).unwrap());
+
+    // long rows are wrapped at 70 characters
+    let mut out = Vec::new();
+    Matrix::from_list(1, 40, vec![100; 40])
+        .write_pgm(
+            &mut out,
+            &PnmOptions {
+                encoding: PnmEncoding::Ascii,
+                ..PnmOptions::default()
+            },
+        )
+        .unwrap();
+    let text = String::from_utf8(out).unwrap();
+    assert!(text.lines().all(|line| line.len() <= 70));
+    assert_eq!(3 + 3, text.lines().count());
+}
+
+#[test]
+fn rescale() {
+    let a = matrix![-50, 0; 50, 150];
+    let mut out = Vec::new();
+
+    // out of range values are refused unless rescaled
+    let err = a.write_pgm(&mut out, &PnmOptions::default()).unwrap_err();
+    assert_eq!(io::ErrorKind::InvalidInput, err.kind());
+
+    out.clear();
+    let options = PnmOptions {
+        encoding: PnmEncoding::Ascii,
+        maxval: 100,
+        rescale: true,
+    };
+    a.write_pgm(&mut out, &options).unwrap();
+    assert_eq!(
+        matrix![0, 25; 50, 100],
+        Matrix::read_pgm(out.as_slice()).unwrap()
+    );
+
+    // a constant image maps to 0
+    out.clear();
+    matrix![7, 7].write_pgm(&mut out, &options).unwrap();
+    assert_eq!(matrix![0, 0], Matrix::read_pgm(out.as_slice()).unwrap());
+}
+
+#[test]
+fn ppm_round_trip() {
+    let channels = [
+        matrix![255, 0; 0, 10],
+        matrix![0, 255; 0, 20],
+        matrix![0, 0; 255, 30],
+    ];
+
+    for encoding in [PnmEncoding::Ascii, PnmEncoding::Binary] {
+        let options = PnmOptions {
+            encoding,
+            ..PnmOptions::default()
+        };
+        let mut out = Vec::new();
+        write_ppm(&channels, &mut out, &options).unwrap();
+
+        assert_eq!(channels, read_ppm(out.as_slice()).unwrap());
+    }
+
+    // samples are interleaved per pixel
+    let mut out = Vec::new();
+    write_ppm(&channels, &mut out, &PnmOptions::default()).unwrap();
+    assert_eq!(b"P6\n2 2\n255\n\xff\x00\x00\x00\xff\x00", &out[..17]);
+}
+
+#[test]
+fn ppm_rescale_shares_range() {
+    let channels = [matrix![0, 10], matrix![20, 10], matrix![-20, 0]];
+    let options = PnmOptions {
+        encoding: PnmEncoding::Ascii,
+        maxval: 4,
+        rescale: true,
+    };
+    let mut out = Vec::new();
+    write_ppm(&channels, &mut out, &options).unwrap();
+
+    assert_eq!(
+        [matrix![2, 3], matrix![4, 3], matrix![0, 2]],
+        read_ppm(out.as_slice()).unwrap()
+    );
+}
+
+#[test]
+#[should_panic]
+fn ppm_channel_shapes() {
+    let channels = [matrix![1, 2], matrix![1, 2], matrix![1; 2]];
+
+    write_ppm(&channels, Vec::new(), &PnmOptions::default()).unwrap();
+}
+
+#[test]
+fn invalid_images() {
+    assert_eq!(
+        "expected a P2 or P5 header",
+        format_message(Matrix::read_pgm(b"P6 1 1 255\n\x00\x00\x00".as_slice()))
+    );
+    assert_eq!(
+        "missing maxval",
+        format_message(Matrix::read_pgm(b"P2 1 1".as_slice()))
+    );
+    assert_eq!(
+        "expected a number for height at byte 5",
+        format_message(Matrix::read_pgm(b"P2 2 x 255\n".as_slice()))
+    );
+    assert_eq!(
+        "maxval must be 1 to 65535, found 70000",
+        format_message(Matrix::read_pgm(b"P2 1 1 70000\n1\n".as_slice()))
+    );
+    assert_eq!(
+        "image must have at least one pixel, found 0 x 2",
+        format_message(Matrix::read_pgm(b"P2 0 2 255\n".as_slice()))
+    );
+    assert_eq!(
+        "sample 300 is larger than maxval 255",
+        format_message(Matrix::read_pgm(b"P2 2 1 255\n1 300\n".as_slice()))
+    );
+    assert_eq!(
+        "missing sample",
+        format_message(Matrix::read_pgm(b"P2 2 2 255\n1 2 3\n".as_slice()))
+    );
+    assert_eq!(
+        "image data is truncated",
+        format_message(Matrix::read_pgm(b"P5 2 2 255\n\x01\x02\x03".as_slice()))
+    );
+    assert_eq!(
+        "image data is truncated",
+        format_message(read_ppm(b"P6 1 1 255\n\x01\x02".as_slice()))
+    );
+}
@@ -0,0 +1,112 @@
+use super::*;
+
+fn sample() -> Matrix {
+    Matrix::new(vec![
+        vec![2, -1, 0, 3],
+        vec![1, 4, -2, 0],
+        vec![0, 5, 1, -1],
+        vec![-3, 0, 2, 2],
+    ])
+}
+
+#[test]
+fn characteristic_polynomial_2x2() {
+    let a = Matrix::new(vec![vec![1, 2], vec![3, 4]]);
+
+    // x^2 - 5x - 2
+    assert_eq!(Ok(vec![-2, -5, 1]), a.characteristic_polynomial());
+}
+
+#[test]
+fn characteristic_polynomial_triangular() {
+    let a = Matrix::new(vec![vec![2, 7, 1], vec![0, 3, 5], vec![0, 0, -1]]);
+
+    // (x - 2)(x - 3)(x + 1) = x^3 - 4x^2 + x + 6
+    assert_eq!(Ok(vec![6, 1, -4, 1]), a.characteristic_polynomial());
+}
+
+#[test]
+fn characteristic_polynomial_matches_trace_and_determinant() {
+    let a = sample();
+
+    let poly = a.characteristic_polynomial().unwrap();
+
+    let trace: isize = (0..4).map(|i| a.cells[i][i]).sum();
+    assert_eq!(5, poly.len());
+    assert_eq!(1, poly[4]);
+    assert_eq!(-trace, poly[3]);
+    assert_eq!(a.determinant().unwrap(), poly[0]);
+}
+
+#[test]
+fn characteristic_polynomial_1x1() {
+    assert_eq!(
+        Ok(vec![-7, 1]),
+        Matrix::new(vec![vec![7]]).characteristic_polynomial()
+    );
+}
+
+#[test]
+fn cayley_hamilton() {
+    let a = sample();
+
+    let poly = a.characteristic_polynomial().unwrap();
+
+    assert_eq!(Matrix::zeroes(4, 4), a.evaluate_polynomial(&poly));
+}
+
+#[test]
+fn minimal_polynomial_identity() {
+    let a: Matrix = Matrix::identity(3);
+
+    assert_eq!(Ok(vec![-1, 1]), a.minimal_polynomial());
+}
+
+#[test]
+fn minimal_polynomial_repeated_eigenvalue() {
+    // one 2 x 2 jordan block for 2 and a 1 x 1 block for 2
+    let a = Matrix::new(vec![vec![2, 1, 0], vec![0, 2, 0], vec![0, 0, 2]]);
+
+    // (x - 2)^2, while the characteristic polynomial is (x - 2)^3
+    assert_eq!(Ok(vec![4, -4, 1]), a.minimal_polynomial());
+    assert_eq!(Ok(vec![-8, 12, -6, 1]), a.characteristic_polynomial());
+}
+
+#[test]
+fn minimal_polynomial_equals_characteristic() {
+    let a = sample();
+
+    let minimal = a.minimal_polynomial().unwrap();
+
+    assert_eq!(a.characteristic_polynomial().unwrap(), minimal);
+    assert_eq!(Matrix::zeroes(4, 4), a.evaluate_polynomial(&minimal));
+}
+
+#[test]
+fn minimal_polynomial_nilpotent() {
+    let a = Matrix::new(vec![vec![0, 1, 0], vec![0, 0, 1], vec![0, 0, 0]]);
+
+    assert_eq!(Ok(vec![0, 0, 0, 1]), a.minimal_polynomial());
+}
+
+#[test]
+fn characteristic_polynomial_overflow() {
+    let a = Matrix::new(vec![vec![isize::MAX, 0], vec![0, isize::MAX]]);
+
+    assert_eq!(Err(MatrixError::Overflow), a.characteristic_polynomial());
+}
+
+#[test]
+fn evaluate_polynomial_rational() {
+    let a = Matrix::new(vec![
+        vec![Rational::new(1, 2), Rational::from_integer(1)],
+        vec![Rational::from_integer(0), Rational::new(1, 3)],
+    ]);
+    let poly = [
+        Rational::new(1, 6),
+        Rational::new(-5, 6),
+        Rational::from_integer(1),
+    ];
+
+    assert_eq!(Matrix::zeroes(2, 2), a.evaluate_polynomial(&poly));
+}
@@ -0,0 +1,114 @@
+use super::*;
+
+fn tall() -> Matrix<f64> {
+    Matrix::new(vec![
+        vec![1.0, 2.0, 0.5],
+        vec![3.0, -1.0, 2.0],
+        vec![0.0, 4.0, 1.0],
+        vec![2.0, 2.0, -3.0],
+        vec![-1.0, 0.5, 1.5],
+    ])
+}
+
+fn is_upper_triangular(r: &Matrix<f64>) -> bool {
+    (0..r.rows()).all(|i| (0..r.cols().min(i)).all(|j| r.cells[i][j] == 0.0))
+}
+
+#[test]
+fn qr_reconstructs() {
+    let a = tall();
+
+    let (q, r) = a.qr();
+
+    assert_eq!((5, 5), (q.rows(), q.cols()));
+    assert_eq!((5, 3), (r.rows(), r.cols()));
+    assert!(is_upper_triangular(&r));
+    assert!((q.clone() * r).approx_eq(&a, 1e-12, 0.0));
+    assert!((q.transpose() * q).approx_eq(&Matrix::identity(5), 1e-12, 0.0));
+}
+
+#[test]
+fn qr_wide() {
+    let a = tall().transpose();
+
+    let (q, r) = a.qr();
+
+    assert_eq!((3, 5), (r.rows(), r.cols()));
+    assert!(is_upper_triangular(&r));
+    assert!((q * r).approx_eq(&a, 1e-12, 0.0));
+}
+
+#[test]
+fn thin_qr_reconstructs() {
+    let a = tall();
+
+    let (q, r) = a.thin_qr();
+
+    assert_eq!((5, 3), (q.rows(), q.cols()));
+    assert_eq!((3, 3), (r.rows(), r.cols()));
+    assert!(is_upper_triangular(&r));
+    assert!((q.clone() * r).approx_eq(&a, 1e-12, 0.0));
+    assert!((q.transpose() * q).approx_eq(&Matrix::identity(3), 1e-12, 0.0));
+}
+
+#[test]
+fn qr_zero_column() {
+    let a = Matrix::new(vec![vec![0.0, 1.0], vec![0.0, 2.0], vec![0.0, 3.0]]);
+
+    let (q, r) = a.qr();
+
+    assert!((q * r).approx_eq(&a, 1e-12, 0.0));
+}
+
+#[test]
+#[should_panic]
+fn thin_qr_wide_panics() {
+    tall().transpose().thin_qr();
+}
+
+#[test]
+fn least_squares_line_fit() {
+    // points on y = 2x + 1 with noise orthogonal to both columns, so the fit recovers the line exactly
+    let a = Matrix::new(vec![
+        vec![0.0, 1.0],
+        vec![1.0, 1.0],
+        vec![2.0, 1.0],
+        vec![3.0, 1.0],
+    ]);
+    let b = Matrix::new(vec![vec![1.5], vec![2.5], vec![4.5], vec![7.5]]);
+
+    let x = a.least_squares(&b).unwrap();
+
+    assert!(x.approx_eq(&Matrix::new(vec![vec![2.0], vec![1.0]]), 1e-12, 0.0));
+}
+
+#[test]
+fn least_squares_consistent_system() {
+    let a = tall();
+    let x = Matrix::new(vec![vec![1.0, -2.0], vec![0.5, 3.0], vec![-1.0, 0.0]]);
+    let b = a.clone() * x.clone();
+
+    assert!(a.least_squares(&b).unwrap().approx_eq(&x, 1e-12, 0.0));
+}
+
+#[test]
+fn least_squares_normal_equations() {
+    let a = tall();
+    let b = Matrix::new(vec![vec![1.0], vec![0.0], vec![-2.0], vec![4.0], vec![3.0]]);
+
+    let x = a.least_squares(&b).unwrap();
+
+    // the residual is orthogonal to the columns of A
+    let fitted = a.clone() * x;
+    let residual = Matrix::from_list(5, 1, (1..=5).map(|r| b[[r, 1]] - fitted[[r, 1]]).collect());
+    let normal = a.transpose() * residual;
+    assert!(normal.approx_eq(&Matrix::zeroes(3, 1), 1e-12, 0.0));
+}
+
+#[test]
+fn least_squares_rank_deficient() {
+    let a = Matrix::new(vec![vec![1.0, 2.0], vec![2.0, 4.0], vec![3.0, 6.0]]);
+    let b = Matrix::new(vec![vec![1.0], vec![2.0], vec![3.0]]);
+
+    assert_eq!(Err(MatrixError::Singular), a.least_squares(&b));
+}
@@ -0,0 +1,118 @@
+use super::*;
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+
+fn r(num: isize, den: isize) -> Rational {
+    Rational::new(num, den)
+}
+
+#[test]
+fn new_normalises() {
+    let a = Rational::new(6, -8);
+
+    assert_eq!(-3, a.numerator());
+    assert_eq!(4, a.denominator());
+}
+
+#[test]
+fn new_zero() {
+    assert_eq!(Rational::from_integer(0), Rational::new(0, -5));
+}
+
+#[test]
+#[should_panic]
+fn new_zero_denominator() {
+    Rational::new(1, 0);
+}
+
+#[test]
+fn arithmetic() {
+    assert_eq!(r(5, 6), r(1, 2) + r(1, 3));
+    assert_eq!(r(1, 6), r(1, 2) - r(1, 3));
+    assert_eq!(r(1, 6), r(1, 2) * r(1, 3));
+    assert_eq!(r(3, 2), r(1, 2) / r(1, 3));
+    assert_eq!(r(-1, 2), -r(1, 2));
+}
+
+#[test]
+fn ordering() {
+    assert!(r(1, 3) < r(1, 2));
+    assert!(r(-1, 2) < r(-1, 3));
+    assert_eq!(Some(r(2, 1)), r(1, 2).recip());
+    assert_eq!(None, Rational::from_integer(0).recip());
+}
+
+#[test]
+fn overflow_is_reduced_away() {
+    // the intermediate products overflow isize but the result fits
+    let big = Rational::new(isize::MAX, 2);
+
+    assert_eq!(Rational::from_integer(isize::MAX), big * r(2, 1));
+}
+
+#[test]
+fn checked_overflow() {
+    let big = Rational::from_integer(isize::MAX);
+
+    assert_eq!(None, big.checked_add(Rational::from_integer(1)));
+    assert_eq!(None, big.checked_mul(big));
+    assert_eq!(None, big.checked_div(Rational::from_integer(0)));
+}
+
+#[test]
+#[should_panic]
+fn add_overflow_panics() {
+    let _ = Rational::from_integer(isize::MAX) + Rational::from_integer(1);
+}
+
+#[test]
+fn display() {
+    assert_eq!("3", format!("{}", r(3, 1)));
+    assert_eq!("-1/2", format!("{}", r(2, -4)));
+}
+
+#[test]
+fn matrix_mul_and_add() {
+    let a = Matrix::new(vec![vec![r(1, 2), r(1, 3)], vec![r(1, 4), r(1, 5)]]);
+    let b = Matrix::new(vec![vec![r(2, 1), r(0, 1)], vec![r(0, 1), r(3, 1)]]);
+
+    let prod = Matrix::new(vec![vec![r(1, 1), r(1, 1)], vec![r(1, 2), r(3, 5)]]);
+    let sum = Matrix::new(vec![vec![r(5, 2), r(1, 3)], vec![r(1, 4), r(16, 5)]]);
+
+    assert_eq!(prod, a.clone() * b.clone());
+    assert_eq!(sum, a + b);
+}
+
+#[test]
+fn matrix_strass() {
+    let a = Matrix::from_list(3, 3, (1..=9).map(|x| r(x, 2)).collect());
+    let b = Matrix::from_list(3, 3, (1..=9).map(|x| r(1, x)).collect());
+
+    assert_eq!(a.clone() * b.clone(), a.strass(&b));
+}
+
+#[test]
+fn matrix_inverse() {
+    let a = Matrix::from_list(2, 2, vec![r(1, 2), r(1, 3), r(1, 4), r(1, 5)]);
+
+    let inv = a.inverse().unwrap();
+
+    assert_eq!(Matrix::identity(2), a.clone() * inv.clone());
+    assert_eq!(Matrix::identity(2), inv * a);
+}
+
+#[test]
+fn matrix_inverse_singular() {
+    let a = Matrix::from_list(2, 2, vec![r(1, 2), r(1, 4), r(1, 1), r(1, 2)]);
+
+    assert_eq!(Err(MatrixError::Singular), a.inverse());
+}
+
+#[test]
+fn matrix_from_integer_inverse() {
+    let a = Matrix::from_list(2, 2, vec![1, 2, 3, 4]);
+
+    let inv: Matrix<Rational> = a.inverse().unwrap().into();
+
+    assert_eq!(Matrix::identity(2), a.map(Rational::from) * inv);
+}
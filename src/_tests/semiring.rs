@@ -0,0 +1,105 @@
+use super::*;
+
+const INF: isize = isize::MAX;
+
+#[test]
+fn arithmetic_matches_mul() {
+    let a = Matrix::from_list(3, 3, (1..=9).collect());
+    let b = Matrix::from_list(3, 3, (1..=9).rev().collect());
+
+    assert_eq!(
+        a.clone() * b.clone(),
+        a.semiring_mul::<Arithmetic<isize>>(&b)
+    );
+}
+
+#[test]
+fn arithmetic_strass() {
+    let a = Matrix::from_list(3, 3, (1..=9).collect());
+    let b = Matrix::from_list(3, 3, (1..=9).rev().collect());
+
+    assert_eq!(a.strass(&b), a.semiring_strass::<Arithmetic<isize>>(&b));
+}
+
+#[test]
+fn min_plus_shortest_paths() {
+    // 1 -> 2 costs 4, 1 -> 3 costs 1, 3 -> 2 costs 2
+    let a = Matrix::new(vec![vec![0, 4, 1], vec![INF, 0, INF], vec![INF, 2, 0]]);
+
+    let mat = Matrix::new(vec![vec![0, 3, 1], vec![INF, 0, INF], vec![INF, 2, 0]]);
+
+    assert_eq!(mat, a.semiring_mul::<MinPlus>(&a));
+}
+
+#[test]
+fn min_plus_identity() {
+    let a = Matrix::new(vec![vec![0, 4], vec![INF, 0]]);
+    let ident = Matrix::semiring_identity::<MinPlus>(2);
+
+    assert_eq!(Matrix::new(vec![vec![0, INF], vec![INF, 0]]), ident);
+    assert_eq!(a, a.semiring_mul::<MinPlus>(&ident));
+}
+
+#[test]
+fn max_plus_scheduling() {
+    // longest chain of task durations, 1 -> 2 takes 3, 1 -> 3 takes 1, 3 -> 2 takes 5
+    let ninf = isize::MIN;
+    let a = Matrix::new(vec![vec![0, 3, 1], vec![ninf, 0, ninf], vec![ninf, 5, 0]]);
+
+    let mat = Matrix::new(vec![vec![0, 6, 1], vec![ninf, 0, ninf], vec![ninf, 5, 0]]);
+
+    assert_eq!(mat, a.semiring_mul::<MaxPlus>(&a));
+}
+
+#[test]
+fn boolean_reachability() {
+    // 1 -> 2 -> 3
+    let a = Matrix::new(vec![
+        vec![false, true, false],
+        vec![false, false, true],
+        vec![false, false, false],
+    ]);
+
+    let mat = Matrix::new(vec![
+        vec![false, false, true],
+        vec![false, false, false],
+        vec![false, false, false],
+    ]);
+
+    assert_eq!(mat, a.semiring_mul::<Boolean>(&a));
+}
+
+#[test]
+fn max_min_bottleneck() {
+    // the widest route from 1 to 2 goes through 3 with capacity min(7, 6)
+    let none = isize::MIN;
+    let a = Matrix::new(vec![
+        vec![INF, 2, 7],
+        vec![none, INF, none],
+        vec![none, 6, INF],
+    ]);
+
+    let mat = Matrix::new(vec![
+        vec![INF, 6, 7],
+        vec![none, INF, none],
+        vec![none, 6, INF],
+    ]);
+
+    assert_eq!(mat, a.semiring_mul::<MaxMin>(&a));
+}
+
+#[test]
+fn asym_semiring_mul() {
+    let a = Matrix::new(vec![vec![1, 2]]);
+    let b = Matrix::new(vec![vec![3], vec![4]]);
+
+    assert_eq!(Matrix::new(vec![vec![4]]), a.semiring_mul::<MinPlus>(&b));
+}
+
+#[test]
+#[should_panic]
+fn semiring_mul_shape_mismatch() {
+    let a = Matrix::new(vec![vec![1, 2]]);
+
+    a.semiring_mul::<MinPlus>(&a);
+}
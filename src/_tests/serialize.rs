@@ -0,0 +1,96 @@
+use serde::Deserialize;
+
+use super::*;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Payload {
+    #[serde(with = "nested")]
+    weights: Matrix<f64>,
+    bias: Matrix<f64>,
+}
+
+fn from_json<'de, T: Deserialize<'de>>(text: &'de str) -> Result<T, String> {
+    serde_json::from_str(text).map_err(|err| err.to_string())
+}
+
+#[test]
+fn matrix_compact_json() {
+    let a = Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+    let text = serde_json::to_string(&a).unwrap();
+
+    assert_eq!(r#"{"rows":2,"cols":3,"data":[1,2,3,4,5,6]}"#, text);
+    assert_eq!(Ok(a), from_json::<Matrix>(&text));
+}
+
+#[test]
+fn matrix_compact_rejects_bad_shapes() {
+    assert_eq!(
+        Err("a 2 x 2 matrix needs 4 entries, found 3".to_string()),
+        from_json::<Matrix>(r#"{"rows":2,"cols":2,"data":[1,2,3]}"#)
+    );
+    assert_eq!(
+        Err("matrix must have at least one row and column".to_string()),
+        from_json::<Matrix>(r#"{"rows":0,"cols":2,"data":[]}"#)
+    );
+    assert!(from_json::<Matrix>(r#"{"rows":4611686018427387904,"cols":4,"data":[]}"#).is_err());
+    assert!(from_json::<Matrix>(r#"{"rows":1,"cols":1}"#).is_err());
+    assert!(from_json::<Matrix>(r#"{"rows":1,"cols":1,"data":[1],"extra":0}"#).is_err());
+}
+
+#[test]
+fn matrix_nested_json() {
+    let payload = Payload {
+        weights: Matrix::new(vec![vec![0.5, -1.0], vec![2.0, 0.25]]),
+        bias: Matrix::new(vec![vec![1.0, 2.0]]),
+    };
+
+    let text = serde_json::to_string(&payload).unwrap();
+
+    assert_eq!(
+        r#"{"weights":[[0.5,-1.0],[2.0,0.25]],"bias":{"rows":1,"cols":2,"data":[1.0,2.0]}}"#,
+        text
+    );
+    assert_eq!(Ok(payload), from_json::<Payload>(&text));
+}
+
+#[test]
+fn matrix_nested_rejects_ragged() {
+    let ragged = r#"{"weights":[[1.0,2.0],[3.0]],"bias":{"rows":1,"cols":1,"data":[1.0]}}"#;
+    let empty = r#"{"weights":[[]],"bias":{"rows":1,"cols":1,"data":[1.0]}}"#;
+
+    assert!(from_json::<Payload>(ragged)
+        .unwrap_err()
+        .starts_with("row 2 has 1 entries, expected 2"));
+    assert!(from_json::<Payload>(empty)
+        .unwrap_err()
+        .starts_with("matrix must have at least one row and column"));
+}
+
+#[test]
+fn blocks_round_trip() {
+    let a = Matrix::from_list(4, 2, (1..=8).collect());
+    let blocks = Blocks::from_matrix(a.clone());
+
+    let text = serde_json::to_string(&blocks).unwrap();
+
+    assert_eq!(text, serde_json::to_string(&a).unwrap());
+    assert_eq!(Ok(blocks), from_json::<Blocks>(&text));
+}
+
+#[test]
+fn blocks_reject_odd_shapes() {
+    assert_eq!(
+        Err("blocks need an even number of rows and columns, found 3 x 2".to_string()),
+        from_json::<Blocks>(r#"{"rows":3,"cols":2,"data":[1,2,3,4,5,6]}"#)
+    );
+}
+
+#[test]
+fn matrix_bincode_round_trip() {
+    let a = Matrix::new(vec![vec![1.5f32, -2.0], vec![0.0, 8.25], vec![3.0, 1.0]]);
+
+    let bytes = bincode::serialize(&a).unwrap();
+
+    assert_eq!(a, bincode::deserialize::<Matrix<f32>>(&bytes).unwrap());
+}
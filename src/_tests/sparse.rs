@@ -0,0 +1,48 @@
+use super::*;
+
+#[test]
+fn from_triplets() {
+    let a = CsrMatrix::from_triplets(2, 3, vec![([2, 3], -7), ([1, 2], 1), ([1, 1], 5)]);
+
+    assert_eq!((2, 3, 3), (a.rows(), a.cols(), a.nnz()));
+    assert_eq!(5, a.get([1, 1]));
+    assert_eq!(0, a.get([2, 1]));
+    assert_eq!(-7, a.get([2, 3]));
+    assert_eq!(
+        vec![([1, 1], 5), ([1, 2], 1), ([2, 3], -7)],
+        a.iter().collect::<Vec<_>>()
+    );
+    assert_eq!(Matrix::new(vec![vec![5, 1, 0], vec![0, 0, -7]]), a.to_matrix());
+}
+
+#[test]
+fn repeated_position_keeps_last() {
+    let a = CsrMatrix::from_triplets(2, 2, vec![([1, 2], 1), ([2, 1], 3), ([1, 2], 4)]);
+
+    assert_eq!(2, a.nnz());
+    assert_eq!(4, a.get([1, 2]));
+}
+
+#[test]
+fn empty_rows() {
+    let a = CsrMatrix::from_triplets(4, 1, vec![([3, 1], 2.5)]);
+
+    assert_eq!(1, a.nnz());
+    assert_eq!(0.0, a.get([1, 1]));
+    assert_eq!(2.5, a.get([3, 1]));
+    assert_eq!(0.0, a.get([4, 1]));
+}
+
+#[test]
+#[should_panic]
+fn triplet_out_of_bounds() {
+    CsrMatrix::from_triplets(2, 2, vec![([3, 1], 1)]);
+}
+
+#[test]
+#[should_panic]
+fn get_out_of_bounds() {
+    let a = CsrMatrix::from_triplets(2, 2, vec![([1, 1], 1)]);
+
+    a.get([1, 3]);
+}
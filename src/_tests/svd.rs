@@ -0,0 +1,132 @@
+use super::*;
+
+fn diag(values: &[f64]) -> Matrix<f64> {
+    let mut out = Matrix::zeroes(values.len(), values.len());
+
+    for (i, &value) in values.iter().enumerate() {
+        out.cells[i][i] = value;
+    }
+
+    out
+}
+
+fn tall() -> Matrix<f64> {
+    Matrix::new(vec![
+        vec![1.0, 2.0, 0.5],
+        vec![3.0, -1.0, 2.0],
+        vec![0.0, 4.0, 1.0],
+        vec![2.0, 2.0, -3.0],
+        vec![-1.0, 0.5, 1.5],
+    ])
+}
+
+fn rank_two() -> Matrix<f64> {
+    // every row is a combination of (1, 2, 3, 4) and (0, 1, 0, -1)
+    Matrix::new(vec![
+        vec![1.0, 2.0, 3.0, 4.0],
+        vec![0.0, 1.0, 0.0, -1.0],
+        vec![2.0, 5.0, 6.0, 7.0],
+        vec![1.0, 0.0, 3.0, 6.0],
+    ])
+}
+
+fn check_svd(a: &Matrix<f64>) {
+    let k = a.rows().min(a.cols());
+
+    let (u, sigma, vt) = a.svd();
+
+    assert_eq!((a.rows(), k), (u.rows(), u.cols()));
+    assert_eq!(k, sigma.len());
+    assert_eq!((k, a.cols()), (vt.rows(), vt.cols()));
+    assert!(sigma.windows(2).all(|pair| pair[0] >= pair[1]));
+    assert!((u.transpose() * u.clone()).approx_eq(&Matrix::identity(k), 1e-12, 0.0));
+    assert!((vt.clone() * vt.transpose()).approx_eq(&Matrix::identity(k), 1e-12, 0.0));
+    assert!((u * diag(&sigma) * vt).approx_eq(a, 1e-12, 0.0));
+}
+
+#[test]
+fn svd_reconstructs() {
+    check_svd(&tall());
+    check_svd(&tall().transpose());
+    check_svd(&rank_two());
+}
+
+#[test]
+fn svd_diagonal() {
+    let a = diag(&[1.0, -3.0, 2.0]);
+
+    let (_, sigma, _) = a.svd();
+
+    assert_eq!(vec![3.0, 2.0, 1.0], sigma);
+}
+
+#[test]
+fn svd_zero_matrix() {
+    check_svd(&Matrix::zeroes(3, 2));
+}
+
+#[test]
+fn norm_2_and_condition_number() {
+    let a = Matrix::new(vec![vec![3.0, 0.0], vec![4.0, 5.0]]);
+
+    // A^T A = [[25, 20], [20, 25]] with eigenvalues 45 and 5
+    assert!((a.norm_2() - 45f64.sqrt()).abs() < 1e-12);
+    assert!((a.condition_number() - 3.0).abs() < 1e-12);
+}
+
+#[test]
+fn condition_number_singular() {
+    let a = Matrix::new(vec![vec![1.0, 2.0], vec![2.0, 4.0]]);
+    let b: Matrix<f64> = Matrix::new(vec![vec![1.0, 0.0], vec![0.0, 1e-10]]);
+
+    assert_eq!(f64::INFINITY, a.condition_number());
+    assert_eq!(f64::INFINITY, rank_two().condition_number());
+    assert_eq!(
+        f64::INFINITY,
+        Matrix::<f64>::zeroes(2, 2).condition_number()
+    );
+    // ill conditioned but regular stays finite
+    assert!((b.condition_number() - 1e10).abs() < 1e-2);
+}
+
+#[test]
+fn rank_numerical_rank_two() {
+    let a = rank_two();
+
+    assert_eq!(2, a.rank_numerical(1e-10));
+    assert_eq!(3, tall().rank_numerical(1e-10));
+    assert_eq!(0, Matrix::<f64>::zeroes(2, 2).rank_numerical(1e-10));
+}
+
+#[test]
+fn pseudo_inverse_full_rank() {
+    let a: Matrix<f64> = Matrix::new(vec![vec![4.0, 7.0], vec![2.0, 6.0]]);
+
+    assert!(a
+        .pseudo_inverse(1e-12)
+        .approx_eq(&a.inverse().unwrap(), 1e-12, 0.0));
+}
+
+#[test]
+fn pseudo_inverse_tall_matches_least_squares() {
+    let a = tall();
+    let b = Matrix::new(vec![vec![1.0], vec![0.0], vec![-2.0], vec![4.0], vec![3.0]]);
+
+    let x = a.pseudo_inverse(1e-12) * b.clone();
+
+    assert!(x.approx_eq(&a.least_squares(&b).unwrap(), 1e-12, 0.0));
+}
+
+#[test]
+fn pseudo_inverse_penrose_conditions() {
+    let a = rank_two();
+
+    let p = a.pseudo_inverse(1e-10);
+
+    assert!((a.clone() * p.clone() * a.clone()).approx_eq(&a, 1e-10, 0.0));
+    assert!((p.clone() * a.clone() * p.clone()).approx_eq(&p, 1e-10, 0.0));
+    let ap = a.clone() * p.clone();
+    assert!(ap.approx_eq(&ap.transpose(), 1e-10, 0.0));
+    let pa = p * a;
+    assert!(pa.approx_eq(&pa.transpose(), 1e-10, 0.0));
+}
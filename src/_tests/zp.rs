@@ -0,0 +1,129 @@
+use super::*;
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+
+type Z7 = Zp<7>;
+type Z101 = Zp<101>;
+
+fn z7_matrix(rows: usize, cols: usize, list: Vec<u64>) -> Matrix<Z7> {
+    Matrix::from_list(rows, cols, list.into_iter().map(Z7::new).collect())
+}
+
+#[test]
+fn new_reduces() {
+    assert_eq!(3, Z7::new(10).value());
+    assert_eq!(4, Z7::from_signed(-3).value());
+}
+
+#[test]
+fn arithmetic() {
+    assert_eq!(Z7::new(1), Z7::new(5) + Z7::new(3));
+    assert_eq!(Z7::new(5), Z7::new(1) - Z7::new(3));
+    assert_eq!(Z7::new(1), Z7::new(5) * Z7::new(3));
+    assert_eq!(Z7::new(4), Z7::new(1) / Z7::new(2));
+    assert_eq!(Z7::new(0), -Z7::new(0));
+}
+
+#[test]
+fn large_modulus_does_not_overflow() {
+    type Big = Zp<18446744073709551557>;
+
+    let a = Big::new(18446744073709551556);
+
+    assert_eq!(Big::new(1), a * a);
+    assert_eq!(Big::new(18446744073709551555), a + a);
+    // the modulus is above i64::MAX, so signed values reduce in wider arithmetic
+    assert_eq!(a, Big::from_signed(-1));
+    assert_eq!(Big::new(i64::MAX as u64), Big::from_signed(i64::MAX));
+    assert_eq!(
+        Big::new(18446744073709551557 - (1 << 63)),
+        Big::from_signed(i64::MIN)
+    );
+}
+
+#[test]
+fn inverse_and_pow() {
+    for x in 1..101 {
+        let a = Z101::new(x);
+
+        assert_eq!(Z101::new(1), a * a.inv().unwrap());
+        assert_eq!(a.inv().unwrap(), a.pow(99));
+    }
+
+    assert_eq!(None, Z101::new(0).inv());
+    assert_eq!(None, Zp::<12>::new(4).inv());
+}
+
+#[test]
+fn composite_modulus() {
+    // still a ring, only division is refused, which is checked by the doc test on Zp
+    type Z12 = Zp<12>;
+
+    assert!(!is_prime(12));
+    assert!(!is_prime(1));
+    assert!(is_prime(2));
+    assert!(is_prime(101));
+    assert!(is_prime(18446744073709551557));
+    // a strong pseudoprime to bases 2 to 11
+    assert!(!is_prime(2152302898747));
+
+    assert_eq!(Z12::new(8), Z12::new(2) * Z12::new(10));
+    assert_eq!(Z12::new(4), Z12::new(2).pow(2).pow(3));
+    assert_eq!(Some(Z12::new(5)), Z12::new(5).inv());
+
+    let a = Matrix::from_list(2, 2, vec![2, 1, 1, 0]);
+    assert_eq!(
+        Matrix::from_list(2, 2, vec![0, 1, 1, 10]),
+        a.inverse_mod(12).unwrap()
+    );
+}
+
+#[test]
+fn matrix_mul_and_strass() {
+    let a = z7_matrix(3, 3, (1..=9).collect());
+    let b = z7_matrix(3, 3, (1..=9).rev().collect());
+
+    let mat = z7_matrix(3, 3, vec![2, 3, 4, 0, 6, 5, 5, 2, 6]);
+
+    assert_eq!(mat, a.clone() * b.clone());
+    assert_eq!(mat, a.strass(&b));
+}
+
+#[test]
+fn matrix_pow() {
+    // fibonacci mod 7
+    let a = z7_matrix(2, 2, vec![1, 1, 1, 0]);
+
+    let mat = z7_matrix(2, 2, vec![5, 6, 6, 6]);
+
+    assert_eq!(mat, a.pow(10));
+}
+
+#[test]
+fn matrix_determinant() {
+    let a = z7_matrix(3, 3, vec![2, 3, 6, 5, 1, 4, 3, 2, 1]);
+
+    // 49 = 0 mod 7
+    assert_eq!(Z7::new(0), a.determinant());
+
+    let b = z7_matrix(2, 2, vec![1, 2, 3, 4]);
+
+    assert_eq!(Z7::from_signed(-2), b.determinant());
+}
+
+#[test]
+fn matrix_inverse() {
+    let a = z7_matrix(2, 2, vec![1, 2, 3, 4]);
+
+    let inv = a.inverse().unwrap();
+
+    assert_eq!(z7_matrix(2, 2, vec![5, 1, 5, 3]), inv);
+    assert_eq!(Matrix::identity(2), a * inv);
+}
+
+#[test]
+fn matrix_inverse_singular() {
+    let a = z7_matrix(3, 3, vec![2, 3, 6, 5, 1, 4, 3, 2, 1]);
+
+    assert_eq!(Err(MatrixError::Singular), a.inverse());
+}
@@ -0,0 +1,59 @@
+use std::cmp::Ordering;
+
+use crate::matrix::Matrix;
+use crate::scalar::Float;
+
+impl<T: Float> Matrix<T> {
+    // tolerance aware comparison
+
+    pub fn approx_eq(&self, other: &Self, abs_tol: T, rel_tol: T) -> bool {
+        // elementwise |a - b| <= max(abs_tol, rel_tol * max(|a|, |b|)), matrices of
+        // different shapes are never equal
+        if self.rows() != other.rows() || self.cols() != other.cols() {
+            return false;
+        }
+
+        self.cells
+            .iter()
+            .flatten()
+            .zip(other.cells.iter().flatten())
+            .all(|(&a, &b)| {
+                let scale = if a.abs() > b.abs() { a.abs() } else { b.abs() };
+                let tol = if abs_tol > rel_tol * scale {
+                    abs_tol
+                } else {
+                    rel_tol * scale
+                };
+
+                (a - b).abs() <= tol
+            })
+    }
+
+    pub fn max_abs_diff(&self, other: &Self) -> T {
+        if self.rows() != other.rows() || self.cols() != other.cols() {
+            panic!(
+                "Matrices must be of the same size to compare. Found {} x {} and {} x {}",
+                self.rows(),
+                self.cols(),
+                other.rows(),
+                other.cols()
+            );
+        }
+
+        self.cells
+            .iter()
+            .flatten()
+            .zip(other.cells.iter().flatten())
+            .map(|(&a, &b)| (a - b).abs())
+            .fold(T::zero(), |acc, diff| match diff.partial_cmp(&acc) {
+                Some(Ordering::Greater) => diff,
+                Some(_) => acc,
+                // either is NaN, and the sum keeps it so one NaN entry poisons the result
+                None => diff + acc,
+            })
+    }
+}
+
+#[cfg(test)]
+#[path = "./_tests/approx.rs"]
+mod tests;
@@ -0,0 +1,202 @@
+use std::io::{Read, Write};
+
+use crate::error::BinaryError;
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+
+// layout, all integers little endian
+//
+//   0  magic        b"MTRX"
+//   4  version      1
+//   5  byte order   b'<', the data is always little endian
+//   6  element tag  BinaryElement::TAG
+//   7  reserved     0
+//   8  rows         u64
+//  16  cols         u64
+//  24  data         rows * cols elements in row major order
+//      checksum     crc32 of everything before it, u32
+//
+// the data starts on an 8 byte boundary so a mapped file can be read in place
+
+const MAGIC: &[u8; 4] = b"MTRX";
+const VERSION: u8 = 1;
+const LITTLE_ENDIAN: u8 = b'<';
+
+pub(crate) const HEADER_LEN: usize = 24;
+pub(crate) const CHECKSUM_LEN: usize = 4;
+
+pub trait BinaryElement: Scalar {
+    const TAG: u8;
+    const SIZE: usize;
+
+    fn write_le(self, out: &mut Vec<u8>);
+    // bytes holds exactly SIZE bytes
+    fn read_le(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_binary_element {
+    ($($t:ty => $stored:ty, $tag:literal),*) => {
+        $(
+            impl BinaryElement for $t {
+                const TAG: u8 = $tag;
+                const SIZE: usize = std::mem::size_of::<$stored>();
+
+                fn write_le(self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&(self as $stored).to_le_bytes());
+                }
+
+                fn read_le(bytes: &[u8]) -> Self {
+                    <$stored>::from_le_bytes(bytes.try_into().unwrap()) as $t
+                }
+            }
+        )*
+    };
+}
+
+// isize is stored as i64 so files do not depend on the platform
+impl_binary_element!(
+    i32 => i32, 1,
+    i64 => i64, 2,
+    isize => i64, 2,
+    f32 => f32, 3,
+    f64 => f64, 4
+);
+
+pub(crate) fn element_name(tag: u8) -> String {
+    match tag {
+        1 => "i32".to_string(),
+        2 => "i64".to_string(),
+        3 => "f32".to_string(),
+        4 => "f64".to_string(),
+        _ => format!("unknown (tag {})", tag),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) struct Header {
+    pub(crate) tag: u8,
+    pub(crate) rows: usize,
+    pub(crate) cols: usize,
+}
+
+impl Header {
+    pub(crate) fn new<T: BinaryElement>(rows: usize, cols: usize) -> Self {
+        Header {
+            tag: T::TAG,
+            rows,
+            cols,
+        }
+    }
+
+    pub(crate) fn encode(&self) -> [u8; HEADER_LEN] {
+        let mut out = [0u8; HEADER_LEN];
+        out[..4].copy_from_slice(MAGIC);
+        out[4] = VERSION;
+        out[5] = LITTLE_ENDIAN;
+        out[6] = self.tag;
+        out[8..16].copy_from_slice(&(self.rows as u64).to_le_bytes());
+        out[16..24].copy_from_slice(&(self.cols as u64).to_le_bytes());
+        out
+    }
+
+    pub(crate) fn decode<T: BinaryElement>(bytes: &[u8; HEADER_LEN]) -> Result<Self, BinaryError> {
+        if &bytes[..4] != MAGIC {
+            return Err(BinaryError::BadMagic);
+        }
+        if bytes[4] != VERSION {
+            return Err(BinaryError::UnsupportedVersion(bytes[4]));
+        }
+        if bytes[5] != LITTLE_ENDIAN {
+            return Err(BinaryError::UnsupportedByteOrder(bytes[5]));
+        }
+        if bytes[6] != T::TAG {
+            return Err(BinaryError::ElementMismatch {
+                expected: T::TAG,
+                found: bytes[6],
+            });
+        }
+
+        let rows = u64::from_le_bytes(bytes[8..16].try_into().unwrap());
+        let cols = u64::from_le_bytes(bytes[16..24].try_into().unwrap());
+
+        // the whole file has to be addressable, even when it is only ever streamed
+        let fits = usize::try_from(rows)
+            .ok()
+            .zip(usize::try_from(cols).ok())
+            .and_then(|(r, c)| r.checked_mul(c)?.checked_mul(T::SIZE))
+            .and_then(|len| len.checked_add(HEADER_LEN + CHECKSUM_LEN))
+            .is_some();
+        if rows == 0 || cols == 0 || !fits {
+            return Err(BinaryError::InvalidShape { rows, cols });
+        }
+
+        Ok(Header {
+            tag: bytes[6],
+            rows: rows as usize,
+            cols: cols as usize,
+        })
+    }
+}
+
+impl<T: BinaryElement> Matrix<T> {
+    // binary
+
+    pub fn write_to<W: Write>(&self, mut writer: W) -> std::io::Result<()> {
+        // written a row at a time, nothing the size of the matrix is buffered
+        let header = Header::new::<T>(self.rows(), self.cols()).encode();
+        let mut crc = crc32fast::Hasher::new();
+        crc.update(&header);
+        writer.write_all(&header)?;
+
+        let mut buf = Vec::with_capacity(self.cols() * T::SIZE);
+        for row in self.cells.iter() {
+            buf.clear();
+            for &cell in row.iter() {
+                cell.write_le(&mut buf);
+            }
+
+            crc.update(&buf);
+            writer.write_all(&buf)?;
+        }
+
+        writer.write_all(&crc.finalize().to_le_bytes())
+    }
+
+    pub fn read_from<R: Read>(mut reader: R) -> Result<Self, BinaryError> {
+        let mut header = [0u8; HEADER_LEN];
+        reader.read_exact(&mut header)?;
+        let mut crc = crc32fast::Hasher::new();
+        crc.update(&header);
+        let header = Header::decode::<T>(&header)?;
+
+        // buffers grow with the data actually read, so a corrupt shape ends in
+        // Truncated rather than a huge allocation
+        let row_len = header.cols * T::SIZE;
+        let mut cells = Vec::new();
+        let mut buf = Vec::new();
+        for _ in 0..header.rows {
+            buf.clear();
+            reader.by_ref().take(row_len as u64).read_to_end(&mut buf)?;
+            if buf.len() < row_len {
+                return Err(BinaryError::Truncated);
+            }
+
+            crc.update(&buf);
+            cells.push(buf.chunks_exact(T::SIZE).map(T::read_le).collect());
+        }
+
+        let mut checksum = [0u8; CHECKSUM_LEN];
+        reader.read_exact(&mut checksum)?;
+        let expected = u32::from_le_bytes(checksum);
+        let found = crc.finalize();
+        if expected != found {
+            return Err(BinaryError::ChecksumMismatch { expected, found });
+        }
+
+        Ok(Matrix::new(cells))
+    }
+}
+
+#[cfg(test)]
+#[path = "./_tests/binary.rs"]
+mod tests;
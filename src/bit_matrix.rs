@@ -0,0 +1,452 @@
+use std::ops::{Add, Index, Mul, Sub};
+
+use crate::error::MatrixError;
+use crate::matrix::{strass_2x2, Matrix};
+
+const WORD: usize = 64;
+
+// number of rows of b combined into each four russians table
+const M4RM_BITS: usize = 8;
+
+// below this size the four russians kernel beats another strassen split
+const STRASS_CUTOFF: usize = 512;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BitMatrix {
+    // matrix over GF(2), each row packed into u64 words with the unused high bits kept zero
+    rows: usize,
+    cols: usize,
+    words: Vec<u64>,
+}
+
+impl BitMatrix {
+    //create
+    pub fn zeroes(rows: usize, cols: usize) -> Self {
+        BitMatrix {
+            rows,
+            cols,
+            words: vec![0; rows * cols.div_ceil(WORD)],
+        }
+    }
+
+    pub fn identity(size: usize) -> Self {
+        let mut out = Self::zeroes(size, size);
+
+        for idx in 1..=size {
+            out.set(idx, idx, true);
+        }
+
+        out
+    }
+
+    pub fn from_list(rows: usize, cols: usize, list: Vec<bool>) -> Self {
+        if list.len() != rows * cols {
+            panic!("Error: creating BitMatrix `from_list` input vector does not match, desired matrix length")
+        }
+
+        let mut out = Self::zeroes(rows, cols);
+
+        for (idx, &bit) in list.iter().enumerate() {
+            out.set(idx / cols + 1, idx % cols + 1, bit);
+        }
+
+        out
+    }
+
+    pub fn from_matrix(mat: &Matrix) -> Self {
+        // entries are reduced mod 2
+        let mut out = Self::zeroes(mat.rows(), mat.cols());
+
+        for r in 1..=mat.rows() {
+            for c in 1..=mat.cols() {
+                out.set(r, c, mat[[r, c]].rem_euclid(2) == 1);
+            }
+        }
+
+        out
+    }
+
+    pub fn to_matrix(&self) -> Matrix {
+        let list = (1..=self.rows)
+            .flat_map(|r| (1..=self.cols).map(move |c| (r, c)))
+            .map(|(r, c)| self[[r, c]] as isize)
+            .collect();
+
+        Matrix::from_list(self.rows, self.cols, list)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut out = Self::zeroes(self.cols, self.rows);
+
+        for r in 0..self.rows {
+            for c in 0..self.cols {
+                if self.bit(r, c) {
+                    out.set(c + 1, r + 1, true);
+                }
+            }
+        }
+
+        out
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: bool) {
+        // 1 indexed to match Index
+        let (word, bit) = self.position(row, col);
+
+        if value {
+            self.words[word] |= 1 << bit;
+        } else {
+            self.words[word] &= !(1 << bit);
+        }
+    }
+}
+
+impl BitMatrix {
+    //internal methods
+
+    fn stride(&self) -> usize {
+        self.cols.div_ceil(WORD)
+    }
+
+    fn position(&self, row: usize, col: usize) -> (usize, usize) {
+        if row == 0 || row > self.rows || col == 0 || col > self.cols {
+            panic!(
+                "index [{}, {}] out of bounds for {} x {} BitMatrix",
+                row, col, self.rows, self.cols
+            )
+        }
+
+        let (row, col) = (row - 1, col - 1);
+
+        (row * self.stride() + col / WORD, col % WORD)
+    }
+
+    fn row(&self, row: usize) -> &[u64] {
+        // 0 indexed
+        let stride = self.stride();
+        &self.words[row * stride..(row + 1) * stride]
+    }
+
+    fn row_mut(&mut self, row: usize) -> &mut [u64] {
+        let stride = self.stride();
+        &mut self.words[row * stride..(row + 1) * stride]
+    }
+
+    fn bit(&self, row: usize, col: usize) -> bool {
+        // 0 indexed
+        self.row(row)[col / WORD] >> (col % WORD) & 1 == 1
+    }
+
+    fn bits(&self, row: usize, col: usize, count: usize) -> usize {
+        // the `count` bits of a row starting at `col`, may straddle two words
+        let words = self.row(row);
+        let (word, shift) = (col / WORD, col % WORD);
+
+        let mut out = words[word] >> shift;
+        if shift + count > WORD {
+            out |= words[word + 1] << (WORD - shift);
+        }
+
+        (out & ((1 << count) - 1)) as usize
+    }
+
+    fn xor_row(&mut self, target: usize, source: usize) {
+        // row[target] ^= row[source], 0 indexed
+        let stride = self.stride();
+
+        for w in 0..stride {
+            self.words[target * stride + w] ^= self.words[source * stride + w];
+        }
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        let stride = self.stride();
+
+        for w in 0..stride {
+            self.words.swap(a * stride + w, b * stride + w);
+        }
+    }
+
+    fn resize(&self, rows: usize, cols: usize) -> Self {
+        // pads with zeroes or truncates, keeping the top left corner
+        let mut out = Self::zeroes(rows, cols);
+        let keep = self.cols.min(cols);
+        let whole = keep / WORD;
+
+        for r in 0..self.rows.min(rows) {
+            let src = self.row(r);
+            let dst = out.row_mut(r);
+
+            dst[..whole].copy_from_slice(&src[..whole]);
+            if !keep.is_multiple_of(WORD) {
+                dst[whole] = src[whole] & ((1 << (keep % WORD)) - 1);
+            }
+        }
+
+        out
+    }
+
+    fn quadrant(&self, row: usize, col: usize, rows: usize, cols: usize) -> Self {
+        // word aligned sub matrix, col and cols are multiples of WORD
+        let mut out = Self::zeroes(rows, cols);
+        let (first, count) = (col / WORD, cols / WORD);
+
+        for r in 0..rows {
+            out.row_mut(r)
+                .copy_from_slice(&self.row(row + r)[first..first + count]);
+        }
+
+        out
+    }
+
+    fn join(quads: [BitMatrix; 4]) -> Self {
+        // inverse of quadrant, the top left block has to be word aligned
+        let [q11, q12, q21, q22] = quads;
+        let mut out = Self::zeroes(q11.rows + q21.rows, q11.cols + q12.cols);
+        let split = q11.stride();
+
+        for (offset, left, right) in [(0, &q11, &q12), (q11.rows, &q21, &q22)] {
+            for r in 0..left.rows {
+                let dst = out.row_mut(offset + r);
+                dst[..split].copy_from_slice(left.row(r));
+                dst[split..].copy_from_slice(right.row(r));
+            }
+        }
+
+        out
+    }
+}
+
+impl BitMatrix {
+    // multiplication
+
+    pub fn m4rm(&self, b: &Self) -> Self {
+        // method of four russians, every group of M4RM_BITS rows of b is expanded into
+        // a table of all their sums so each row of a needs one lookup per group
+        if self.cols != b.rows {
+            panic!("Matrix multiplaicaton only allowed for r x m and m x c matrices to form r x c matrix.\n Found {} x {} and {} x {} matrices", self.rows, self.cols, b.rows, b.cols)
+        }
+
+        let mut out = Self::zeroes(self.rows, b.cols);
+        let stride = b.stride();
+        let mut table = vec![0u64; (1 << M4RM_BITS) * stride];
+
+        for start in (0..self.cols).step_by(M4RM_BITS) {
+            let count = M4RM_BITS.min(self.cols - start);
+
+            for idx in 1..(1usize << count) {
+                // each entry is an earlier entry plus the row for its lowest set bit
+                let low = idx.trailing_zeros() as usize;
+                let prev = idx & (idx - 1);
+
+                for w in 0..stride {
+                    table[idx * stride + w] = table[prev * stride + w] ^ b.row(start + low)[w];
+                }
+            }
+
+            for r in 0..self.rows {
+                let idx = self.bits(r, start, count);
+
+                if idx != 0 {
+                    let lookup = &table[idx * stride..(idx + 1) * stride];
+
+                    for (cell, &t) in out.row_mut(r).iter_mut().zip(lookup) {
+                        *cell ^= t;
+                    }
+                }
+            }
+        }
+
+        out
+    }
+
+    pub fn strass(&self, b: &Self) -> Self {
+        // one strassen split on word aligned quadrants, the seven products go back
+        // through Mul so they keep splitting until they fall under STRASS_CUTOFF
+        if self.cols != b.rows {
+            panic!("Matrix multiplaicaton only allowed for r x m and m x c matrices to form r x c matrix.\n Found {} x {} and {} x {} matrices", self.rows, self.cols, b.rows, b.cols)
+        }
+
+        let (out_rows, out_cols) = (self.rows, b.cols);
+
+        // find the closest multiple of two words
+        let pad = |size: usize| size.div_ceil(2 * WORD) * 2 * WORD;
+        let (m, k, n) = (pad(self.rows), pad(self.cols), pad(b.cols));
+
+        let a = self.resize(m, k);
+        let b = b.resize(k, n);
+
+        let (m, k, n) = (m / 2, k / 2, n / 2);
+
+        let res = BitMatrix::join(strass_2x2(
+            [
+                a.quadrant(0, 0, m, k),
+                a.quadrant(0, k, m, k),
+                a.quadrant(m, 0, m, k),
+                a.quadrant(m, k, m, k),
+            ],
+            [
+                b.quadrant(0, 0, k, n),
+                b.quadrant(0, n, k, n),
+                b.quadrant(k, 0, k, n),
+                b.quadrant(k, n, k, n),
+            ],
+        ));
+
+        res.resize(out_rows, out_cols)
+    }
+}
+
+impl BitMatrix {
+    // elimination over GF(2)
+
+    pub fn rank(&self) -> usize {
+        let mut reduced = self.clone();
+        reduced.row_reduce(None).len()
+    }
+
+    pub fn inverse(&self) -> Result<Self, MatrixError> {
+        if self.rows != self.cols {
+            panic!(
+                "Only square matrices can be inverted. Found {} x {}",
+                self.rows, self.cols
+            )
+        }
+
+        let mut reduced = self.clone();
+        let mut out = Self::identity(self.rows);
+
+        if reduced.row_reduce(Some(&mut out)).len() != self.rows {
+            return Err(MatrixError::Singular);
+        }
+
+        Ok(out)
+    }
+
+    pub fn nullspace(&self) -> Self {
+        // basis of { x : A x = 0 }, one vector per row of the result
+        let mut reduced = self.clone();
+        let pivots = reduced.row_reduce(None);
+
+        let free: Vec<usize> = (0..self.cols).filter(|c| !pivots.contains(c)).collect();
+        let mut out = Self::zeroes(free.len(), self.cols);
+
+        for (idx, &f) in free.iter().enumerate() {
+            out.set(idx + 1, f + 1, true);
+
+            for (r, &p) in pivots.iter().enumerate() {
+                if reduced.bit(r, f) {
+                    out.set(idx + 1, p + 1, true);
+                }
+            }
+        }
+
+        out
+    }
+
+    fn row_reduce(&mut self, mut mirror: Option<&mut BitMatrix>) -> Vec<usize> {
+        // in place reduced row echelon form, repeating every row op on mirror,
+        // returns the pivot column of each non zero row
+        let mut pivots = vec![];
+
+        for col in 0..self.cols {
+            let row = pivots.len();
+
+            let pivot = match (row..self.rows).find(|&r| self.bit(r, col)) {
+                Some(r) => r,
+                None => continue,
+            };
+
+            self.swap_rows(row, pivot);
+            if let Some(m) = mirror.as_deref_mut() {
+                m.swap_rows(row, pivot);
+            }
+
+            for r in (0..self.rows).filter(|&r| r != row) {
+                if self.bit(r, col) {
+                    self.xor_row(r, row);
+                    if let Some(m) = mirror.as_deref_mut() {
+                        m.xor_row(r, row);
+                    }
+                }
+            }
+
+            pivots.push(col);
+
+            if pivots.len() == self.rows {
+                break;
+            }
+        }
+
+        pivots
+    }
+}
+
+impl Index<[usize; 2]> for BitMatrix {
+    type Output = bool;
+
+    fn index(&self, index: [usize; 2]) -> &Self::Output {
+        let (word, bit) = self.position(index[0], index[1]);
+
+        if self.words[word] >> bit & 1 == 1 {
+            &true
+        } else {
+            &false
+        }
+    }
+}
+
+impl Mul for BitMatrix {
+    type Output = Self;
+
+    fn mul(self, b: Self) -> Self::Output {
+        if self.rows.min(self.cols).min(b.cols) >= STRASS_CUTOFF {
+            self.strass(&b)
+        } else {
+            self.m4rm(&b)
+        }
+    }
+}
+
+impl Add for BitMatrix {
+    type Output = Self;
+
+    fn add(self, b: Self) -> Self::Output {
+        if self.rows != b.rows || self.cols != b.cols {
+            panic!(
+                "Matrices must be of the smae size to add. Found {} x {} + {} x {}",
+                self.rows, self.cols, b.rows, b.cols
+            );
+        }
+
+        let mut out = self;
+
+        for (cell, w) in out.words.iter_mut().zip(b.words) {
+            *cell ^= w;
+        }
+
+        out
+    }
+}
+
+impl Sub for BitMatrix {
+    type Output = Self;
+
+    // subtraction is addition in GF(2)
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, b: Self) -> Self::Output {
+        self + b
+    }
+}
+
+#[cfg(test)]
+#[path = "./_tests/bit_matrix.rs"]
+mod tests;
@@ -0,0 +1,110 @@
+use std::cmp::Ordering;
+
+use crate::error::MatrixError;
+use crate::lu::{back_substitute, forward_substitute};
+use crate::matrix::Matrix;
+use crate::scalar::Float;
+
+impl<T: Float> Matrix<T> {
+    // cholesky decomposition, only the lower triangle of A is read
+
+    #[allow(clippy::needless_range_loop)]
+    pub fn cholesky(&self) -> Result<Self, MatrixError> {
+        // A = L L^T with L lower triangular and a positive diagonal
+        let n = self.assert_square("Cholesky decomposition");
+        let a = &self.cells;
+        let mut l = Matrix::zeroes(n, n);
+
+        for j in 0..n {
+            let d = (0..j).fold(a[j][j], |acc, k| acc - l.cells[j][k] * l.cells[j][k]);
+
+            if d.partial_cmp(&self.pivot_tolerance(j, j)) != Some(Ordering::Greater) {
+                return Err(MatrixError::NotPositiveDefinite { pivot: j + 1 });
+            }
+
+            let d = d.sqrt();
+            l.cells[j][j] = d;
+
+            for i in (j + 1)..n {
+                let s = (0..j).fold(a[i][j], |acc, k| acc - l.cells[i][k] * l.cells[j][k]);
+                l.cells[i][j] = s / d;
+            }
+        }
+
+        Ok(l)
+    }
+
+    pub fn cholesky_solve(&self, b: &Self) -> Result<Self, MatrixError> {
+        // solves A X = B through L Y = B and L^T X = Y
+        let l = self.cholesky()?;
+        let n = l.rows();
+
+        if b.rows() != n {
+            panic!(
+                "Right hand side must have {} rows to solve. Found {} x {}",
+                n,
+                b.rows(),
+                b.cols()
+            )
+        }
+
+        let mut x = b.cells.clone();
+        forward_substitute(&mut x, false, |i, k| l.cells[i][k]);
+        back_substitute(&mut x, |i, k| l.cells[k][i]);
+
+        Ok(Matrix::new(x))
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub fn ldl(&self) -> Result<(Self, Self), MatrixError> {
+        // A = L D L^T with L unit lower triangular and D diagonal, returned as (L, D).
+        // zero pivots are allowed so positive semidefinite inputs factor too, as long
+        // as the rest of their column is zero as well
+        let n = self.assert_square("LDL decomposition");
+        let a = &self.cells;
+        let mut l = Matrix::identity(n);
+        let mut d = vec![T::zero(); n];
+
+        for j in 0..n {
+            let dj = (0..j).fold(a[j][j], |acc, k| acc - l.cells[j][k] * l.cells[j][k] * d[k]);
+            let tol = self.pivot_tolerance(j, j);
+
+            if dj.partial_cmp(&-tol) == Some(Ordering::Less) {
+                return Err(MatrixError::NotPositiveDefinite { pivot: j + 1 });
+            }
+
+            let zero_pivot = dj.partial_cmp(&tol) != Some(Ordering::Greater);
+            d[j] = if zero_pivot { T::zero() } else { dj };
+
+            for i in (j + 1)..n {
+                let s = (0..j).fold(a[i][j], |acc, k| acc - l.cells[i][k] * l.cells[j][k] * d[k]);
+
+                if !zero_pivot {
+                    l.cells[i][j] = s / dj;
+                } else if s.abs() > self.pivot_tolerance(i, j) {
+                    return Err(MatrixError::NotPositiveDefinite { pivot: j + 1 });
+                }
+            }
+        }
+
+        let mut diagonal = Matrix::zeroes(n, n);
+        for (i, &dj) in d.iter().enumerate() {
+            diagonal.cells[i][i] = dj;
+        }
+
+        Ok((l, diagonal))
+    }
+
+    fn pivot_tolerance(&self, i: usize, j: usize) -> T {
+        // rounding error on an entry of row i and column j. it scales with the diagonal
+        // entries involved rather than the largest one, since the factorisation does not
+        // change when rows and columns are rescaled
+        let scale = (self.cells[i][i].abs() * self.cells[j][j].abs()).sqrt();
+
+        scale * T::epsilon() * T::from_f64(self.rows() as f64)
+    }
+}
+
+#[cfg(test)]
+#[path = "./_tests/cholesky.rs"]
+mod tests;
@@ -0,0 +1,147 @@
+use std::fmt::Display;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::str::FromStr;
+
+use crate::error::ReadError;
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CsvOptions {
+    pub delimiter: char,
+    // the first data line holds column names, it is skipped but still sets the width
+    pub header: bool,
+    // lines starting with this character are ignored, as are blank lines
+    pub comment: Option<char>,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        CsvOptions {
+            delimiter: ',',
+            header: false,
+            comment: Some('#'),
+        }
+    }
+}
+
+impl CsvOptions {
+    pub fn tsv() -> Self {
+        CsvOptions {
+            delimiter: '\t',
+            ..CsvOptions::default()
+        }
+    }
+}
+
+impl<T: Scalar + FromStr + Display> Matrix<T> {
+    // csv
+
+    pub fn from_csv<R: Read>(reader: R, options: &CsvOptions) -> Result<Self, ReadError> {
+        // every row must have as many fields as the first, fields are trimmed and there
+        // is no quoting since every field has to be a number
+        let mut reader = BufReader::new(reader);
+        let mut cells: Vec<Vec<T>> = Vec::new();
+        let mut width = None;
+        let mut skip_header = options.header;
+        let mut line = String::new();
+        let mut line_no = 0;
+
+        loop {
+            line.clear();
+            if reader.read_line(&mut line)? == 0 {
+                break;
+            }
+            line_no += 1;
+
+            let text = line.trim_end_matches(['\n', '\r']);
+            let trimmed = text.trim_start();
+            if trimmed.is_empty() || options.comment.is_some_and(|c| trimmed.starts_with(c)) {
+                continue;
+            }
+
+            let fields = split_fields(text, options.delimiter);
+
+            match width {
+                None => width = Some(fields.len()),
+                Some(width) if fields.len() > width => {
+                    return Err(ReadError::parse(
+                        line_no,
+                        fields[width].0,
+                        format!("expected {} fields, found {}", width, fields.len()),
+                    ));
+                }
+                Some(width) if fields.len() < width => {
+                    return Err(ReadError::parse(
+                        line_no,
+                        text.chars().count() + 1,
+                        format!("expected {} fields, found {}", width, fields.len()),
+                    ));
+                }
+                Some(_) => {}
+            }
+
+            if skip_header {
+                skip_header = false;
+                continue;
+            }
+
+            let row = fields
+                .iter()
+                .map(|&(column, field)| {
+                    if field.is_empty() {
+                        return Err(ReadError::parse(line_no, column, "empty field"));
+                    }
+
+                    field.parse().map_err(|_| {
+                        ReadError::parse(line_no, column, format!("invalid number `{}`", field))
+                    })
+                })
+                .collect::<Result<_, _>>()?;
+
+            cells.push(row);
+        }
+
+        if cells.is_empty() {
+            return Err(ReadError::parse(line_no + 1, 1, "no data rows"));
+        }
+
+        Ok(Matrix::new(cells))
+    }
+
+    pub fn to_csv<W: Write>(&self, writer: W) -> std::io::Result<()> {
+        self.to_csv_with_delimiter(writer, ',')
+    }
+
+    pub fn to_csv_with_delimiter<W: Write>(
+        &self,
+        mut writer: W,
+        delimiter: char,
+    ) -> std::io::Result<()> {
+        for row in self.cells.iter() {
+            let line: Vec<String> = row.iter().map(|cell| cell.to_string()).collect();
+            writeln!(writer, "{}", line.join(&delimiter.to_string()))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn split_fields(line: &str, delimiter: char) -> Vec<(usize, &str)> {
+    // trimmed fields with the 1 based column where their text starts
+    let mut out = Vec::new();
+    let mut column = 1;
+
+    for field in line.split(delimiter) {
+        let leading = field.len() - field.trim_start().len();
+        let start = column + field[..leading].chars().count();
+        out.push((start, field.trim()));
+        column += field.chars().count() + 1;
+    }
+
+    out
+}
+
+#[cfg(test)]
+#[path = "./_tests/csv.rs"]
+mod tests;
@@ -0,0 +1,83 @@
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+use crate::scalar::Field;
+
+impl Matrix {
+    // determinants
+
+    pub fn determinant(&self) -> Result<isize, MatrixError> {
+        // fraction free (bareiss) elimination so every intermediate stays an integer
+        let n = self.assert_square("Determinant");
+        let mut cells = self.cells.clone();
+        let mut prev = 1isize;
+        let mut sign = 1isize;
+
+        for k in 0..n {
+            let pivot = match (k..n).find(|&r| cells[r][k] != 0) {
+                Some(r) => r,
+                None => return Ok(0),
+            };
+
+            if pivot != k {
+                cells.swap(k, pivot);
+                sign = -sign;
+            }
+
+            for i in (k + 1)..n {
+                for j in (k + 1)..n {
+                    let lhs = cells[k][k].checked_mul(cells[i][j]);
+                    let rhs = cells[i][k].checked_mul(cells[k][j]);
+
+                    cells[i][j] = lhs
+                        .zip(rhs)
+                        .and_then(|(lhs, rhs)| lhs.checked_sub(rhs))
+                        .and_then(|x| x.checked_div(prev))
+                        .ok_or(MatrixError::Overflow)?;
+                }
+            }
+
+            prev = cells[k][k];
+        }
+
+        prev.checked_mul(sign).ok_or(MatrixError::Overflow)
+    }
+}
+
+impl<T: Field> Matrix<T> {
+    pub fn determinant(&self) -> T {
+        // gaussian elimination, the determinant is the product of the pivots
+        let n = self.assert_square("Determinant");
+        let mut cells = self.cells.clone();
+        let mut det = T::one();
+
+        for k in 0..n {
+            let pivot = match (k..n).find(|&r| cells[r][k] != T::zero()) {
+                Some(r) => r,
+                None => return T::zero(),
+            };
+
+            if pivot != k {
+                cells.swap(k, pivot);
+                det = -det;
+            }
+
+            det = det * cells[k][k];
+
+            let pivot_row = cells[k].clone();
+
+            for row in cells.iter_mut().skip(k + 1) {
+                let factor = row[k] / pivot_row[k];
+
+                for (cell, &p) in row.iter_mut().zip(pivot_row.iter()).skip(k) {
+                    *cell = *cell - factor * p;
+                }
+            }
+        }
+
+        det
+    }
+}
+
+#[cfg(test)]
+#[path = "./_tests/determinant.rs"]
+mod tests;
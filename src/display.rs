@@ -0,0 +1,187 @@
+use std::fmt::{self, Display};
+
+use crate::matrix::{Blocks, Matrix};
+use crate::scalar::Scalar;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PrintOptions {
+    // digits after the decimal point, a precision in the format string ({:.3}) wins
+    pub precision: Option<usize>,
+    // matrices with more cells than this are summarised with `...`
+    pub threshold: usize,
+    // rows and columns kept at each edge of a summarised matrix
+    pub edge_items: usize,
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        PrintOptions {
+            precision: None,
+            threshold: 1000,
+            edge_items: 3,
+        }
+    }
+}
+
+pub struct MatrixDisplay<'a, T> {
+    matrix: &'a Matrix<T>,
+    options: PrintOptions,
+}
+
+pub struct BlocksDisplay<T> {
+    matrix: Matrix<T>,
+    options: PrintOptions,
+}
+
+impl<T: Display> Matrix<T> {
+    pub fn display(&self, options: &PrintOptions) -> MatrixDisplay<'_, T> {
+        MatrixDisplay {
+            matrix: self,
+            options: options.clone(),
+        }
+    }
+}
+
+impl<T: Scalar + Display> Blocks<T> {
+    pub fn display(&self, options: &PrintOptions) -> BlocksDisplay<T> {
+        BlocksDisplay {
+            matrix: self.clone().to_matrix(),
+            options: options.clone(),
+        }
+    }
+}
+
+impl<T: Display> Display for Matrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_grid(f, &self.cells, &PrintOptions::default(), None)
+    }
+}
+
+impl<T: Scalar + Display> Display for Blocks<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(&PrintOptions::default()).fmt(f)
+    }
+}
+
+impl<T: Display> Display for MatrixDisplay<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_grid(f, &self.matrix.cells, &self.options, None)
+    }
+}
+
+impl<T: Display> Display for BlocksDisplay<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_grid(f, &self.matrix.cells, &self.options, Some(2))
+    }
+}
+
+fn write_grid<T: Display>(
+    f: &mut fmt::Formatter<'_>,
+    cells: &[Vec<T>],
+    options: &PrintOptions,
+    block: Option<usize>,
+) -> fmt::Result {
+    // numpy style, every column is right aligned to its widest entry. with a block size
+    // the tiles are outlined and a summary keeps whole tiles at the edges
+    let precision = f.precision().or(options.precision);
+    let summarise = cells.len() * cells[0].len() > options.threshold;
+    let edge = match block {
+        Some(size) => options.edge_items.max(1).div_ceil(size) * size,
+        None => options.edge_items.max(1),
+    };
+
+    let rows = shown(cells.len(), edge, summarise);
+    let cols = shown(cells[0].len(), edge, summarise);
+
+    // None marks an elided run of rows or columns
+    let text: Vec<Option<Vec<Option<String>>>> = rows
+        .iter()
+        .map(|row| {
+            row.map(|r| {
+                cols.iter()
+                    .map(|col| {
+                        col.map(|c| match precision {
+                            Some(precision) => format!("{:.*}", precision, cells[r][c]),
+                            None => cells[r][c].to_string(),
+                        })
+                    })
+                    .collect()
+            })
+        })
+        .collect();
+
+    let widths: Vec<usize> = (0..cols.len())
+        .map(|idx| {
+            text.iter()
+                .flatten()
+                .map(|row| row[idx].as_ref().map_or(3, |cell| cell.chars().count()))
+                .max()
+                .unwrap_or(3)
+        })
+        .collect();
+
+    let boundary = |prev: Option<usize>, next: Option<usize>| match (block, prev, next) {
+        (Some(size), Some(prev), Some(next)) => next == prev + 1 && next.is_multiple_of(size),
+        _ => false,
+    };
+
+    for (idx, row) in text.iter().enumerate() {
+        let open = if idx == 0 { "[[" } else { " [" };
+
+        if idx > 0 && boundary(rows[idx - 1], rows[idx]) {
+            write!(f, " [")?;
+            for (c, width) in widths.iter().enumerate() {
+                let separator = match c {
+                    0 => "",
+                    _ if boundary(cols[c - 1], cols[c]) => "-+-",
+                    _ => "-",
+                };
+                write!(f, "{}{}", separator, "-".repeat(*width))?;
+            }
+            writeln!(f, "]")?;
+        }
+
+        let row = match row {
+            Some(row) => row,
+            None => {
+                writeln!(f, " ...")?;
+                continue;
+            }
+        };
+
+        write!(f, "{}", open)?;
+        for (c, (cell, width)) in row.iter().zip(&widths).enumerate() {
+            let separator = match c {
+                0 => "",
+                _ if boundary(cols[c - 1], cols[c]) => " | ",
+                _ => " ",
+            };
+            let cell = cell.as_deref().unwrap_or("...");
+            write!(f, "{}{:>width$}", separator, cell, width = width)?;
+        }
+
+        if idx + 1 == text.len() {
+            write!(f, "]]")?;
+        } else {
+            writeln!(f, "]")?;
+        }
+    }
+
+    Ok(())
+}
+
+fn shown(len: usize, edge: usize, summarise: bool) -> Vec<Option<usize>> {
+    if summarise && len > 2 * edge {
+        (0..edge)
+            .map(Some)
+            .chain([None])
+            .chain((len - edge..len).map(Some))
+            .collect()
+    } else {
+        (0..len).map(Some).collect()
+    }
+}
+
+#[cfg(test)]
+#[path = "./_tests/display.rs"]
+mod tests;
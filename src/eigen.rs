@@ -0,0 +1,323 @@
+use std::cmp::Ordering;
+
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+use crate::qr::{householder_vector, reflect_cols, reflect_rows};
+use crate::scalar::Float;
+
+// cyclic jacobi converges quadratically, so this is only hit by NaN input
+const JACOBI_MAX_SWEEPS: usize = 100;
+
+// francis steps allowed per eigenvalue, an exceptional shift is tried every tenth
+const SCHUR_MAX_ITERATIONS: usize = 100;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T,
+}
+
+impl<T: Float> Matrix<T> {
+    // eigen decomposition
+
+    // the rotations touch rows and columns p and q of the same matrix together
+    #[allow(clippy::needless_range_loop)]
+    pub fn symmetric_eigen(&self) -> (Vec<T>, Self) {
+        // returns the eigenvalues in ascending order and V with the matching orthonormal
+        // eigenvectors as its columns, so A = V diag(values) V^T. only the symmetric part
+        // of A is meaningful, the input is not checked for symmetry
+        let n = self.assert_square("Symmetric eigen decomposition");
+        let mut a = self.cells.clone();
+        let mut v: Matrix<T> = Matrix::identity(n);
+
+        let total = a.iter().flatten().fold(T::zero(), |acc, &x| acc + x * x);
+        let tol = total * T::epsilon() * T::epsilon();
+
+        for _ in 0..JACOBI_MAX_SWEEPS {
+            let off = (0..n).fold(T::zero(), |acc, p| {
+                ((p + 1)..n).fold(acc, |acc, q| acc + a[p][q] * a[p][q])
+            });
+
+            if off.partial_cmp(&tol) != Some(Ordering::Greater) {
+                break;
+            }
+
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    if a[p][q] == T::zero() {
+                        continue;
+                    }
+
+                    let (c, s) = jacobi_rotation(a[p][p], a[q][q], a[p][q]);
+
+                    // A <- J^T A J, then V <- V J
+                    for k in 0..n {
+                        let (kp, kq) = (a[k][p], a[k][q]);
+                        a[k][p] = c * kp - s * kq;
+                        a[k][q] = s * kp + c * kq;
+                    }
+                    for k in 0..n {
+                        let (pk, qk) = (a[p][k], a[q][k]);
+                        a[p][k] = c * pk - s * qk;
+                        a[q][k] = s * pk + c * qk;
+                    }
+                    for k in 0..n {
+                        let (kp, kq) = (v.cells[k][p], v.cells[k][q]);
+                        v.cells[k][p] = c * kp - s * kq;
+                        v.cells[k][q] = s * kp + c * kq;
+                    }
+
+                    a[p][q] = T::zero();
+                    a[q][p] = T::zero();
+                }
+            }
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&x, &y| a[x][x].partial_cmp(&a[y][y]).unwrap_or(Ordering::Equal));
+
+        let values = order.iter().map(|&i| a[i][i]).collect();
+        let vectors = Matrix::new(
+            v.cells
+                .iter()
+                .map(|row| order.iter().map(|&i| row[i]).collect())
+                .collect(),
+        );
+
+        (values, vectors)
+    }
+}
+
+impl<T: Float> Matrix<T> {
+    // non symmetric eigenvalues
+
+    pub fn hessenberg(&self) -> (Self, Self) {
+        // A = Q H Q^T with Q orthogonal and H zero below the first subdiagonal
+        let n = self.assert_square("Hessenberg reduction");
+        let mut h = self.cells.clone();
+        let mut q = Matrix::<T>::identity(n).cells;
+
+        for k in 0..n.saturating_sub(2) {
+            let x: Vec<T> = ((k + 1)..n).map(|i| h[i][k]).collect();
+            let (v, _) = householder_vector(&x);
+
+            reflect_rows(&mut h, &v, k + 1, k, n);
+            reflect_cols(&mut h, &v, k + 1, 0, n);
+            reflect_cols(&mut q, &v, k + 1, 0, n);
+
+            for row in h.iter_mut().skip(k + 2) {
+                row[k] = T::zero();
+            }
+        }
+
+        (Matrix::new(q), Matrix::new(h))
+    }
+
+    pub fn schur(&self) -> Result<(Self, Self), MatrixError> {
+        // real schur form A = Q T Q^T, T is upper triangular apart from 2 x 2 blocks on the
+        // diagonal that hold complex conjugate pairs. francis double shift qr on the
+        // hessenberg form, deflating from the bottom
+        let (q, h) = self.hessenberg();
+        let n = h.rows();
+        let (mut q, mut h) = (q.cells, h.cells);
+
+        let norm = h.iter().flatten().fold(T::zero(), |acc, &x| acc + x.abs());
+        let mut hi = n.saturating_sub(1);
+        let mut iterations = 0;
+
+        while hi > 0 {
+            // the active block is lo..=hi, split off where a subdiagonal entry is negligible
+            let mut lo = hi;
+            while lo > 0 {
+                let mut scale = h[lo - 1][lo - 1].abs() + h[lo][lo].abs();
+                if scale == T::zero() {
+                    scale = norm;
+                }
+                if h[lo][lo - 1].abs() <= T::epsilon() * scale {
+                    h[lo][lo - 1] = T::zero();
+                    break;
+                }
+                lo -= 1;
+            }
+
+            if lo == hi {
+                hi -= 1;
+                iterations = 0;
+                continue;
+            }
+
+            if lo + 1 == hi {
+                standardise_block(&mut h, &mut q, lo);
+                hi = hi.saturating_sub(2);
+                iterations = 0;
+                continue;
+            }
+
+            iterations += 1;
+            if iterations > SCHUR_MAX_ITERATIONS {
+                return Err(MatrixError::NoConvergence);
+            }
+
+            francis_step(&mut h, &mut q, lo, hi, iterations.is_multiple_of(10));
+        }
+
+        for (i, row) in h.iter_mut().enumerate() {
+            for cell in row.iter_mut().take(i.saturating_sub(1)) {
+                *cell = T::zero();
+            }
+        }
+
+        Ok((Matrix::new(q), Matrix::new(h)))
+    }
+
+    pub fn eigenvalues(&self) -> Result<Vec<Complex<T>>, MatrixError> {
+        // in the order they appear on the diagonal of the schur form, conjugate pairs are
+        // adjacent with the positive imaginary part first
+        let (_, t) = self.schur()?;
+        let t = t.cells;
+        let n = t.len();
+        let mut values = Vec::with_capacity(n);
+        let mut i = 0;
+
+        while i < n {
+            if i + 1 < n && t[i + 1][i] != T::zero() {
+                let (re, im) =
+                    block_eigenvalues(t[i][i], t[i][i + 1], t[i + 1][i], t[i + 1][i + 1]);
+                values.push(Complex { re, im });
+                values.push(Complex { re, im: -im });
+                i += 2;
+            } else {
+                values.push(Complex {
+                    re: t[i][i],
+                    im: T::zero(),
+                });
+                i += 1;
+            }
+        }
+
+        Ok(values)
+    }
+}
+
+pub(crate) fn jacobi_rotation<T: Float>(app: T, aqq: T, apq: T) -> (T, T) {
+    // cosine and sine of the rotation that zeroes apq, taking the smaller angle. the svd
+    // passes column norms and the column dot product to orthogonalise a pair of columns
+    let two = T::one() + T::one();
+    let theta = (aqq - app) / (two * apq);
+    let t = T::one() / (theta.abs() + (theta * theta + T::one()).sqrt());
+    let t = if theta < T::zero() { -t } else { t };
+    let c = T::one() / (t * t + T::one()).sqrt();
+
+    (c, t * c)
+}
+
+fn francis_step<T: Float>(
+    h: &mut [Vec<T>],
+    q: &mut [Vec<T>],
+    lo: usize,
+    hi: usize,
+    exceptional: bool,
+) {
+    // one implicit double shift step on rows and columns lo..=hi, the shifts are the
+    // eigenvalues of the trailing 2 x 2 block and only enter through their sum and product
+    let n = h.len();
+    let (trace, det) = if exceptional {
+        // breaks cycles that the standard shifts can get stuck in
+        let s = h[hi][hi - 1].abs() + h[hi - 1][hi - 2].abs();
+        (T::from_f64(1.5) * s, s * s)
+    } else {
+        (
+            h[hi - 1][hi - 1] + h[hi][hi],
+            h[hi - 1][hi - 1] * h[hi][hi] - h[hi - 1][hi] * h[hi][hi - 1],
+        )
+    };
+
+    // first column of (H - s1 I)(H - s2 I)
+    let mut x = h[lo][lo] * h[lo][lo] + h[lo][lo + 1] * h[lo + 1][lo] - trace * h[lo][lo] + det;
+    let mut y = h[lo + 1][lo] * (h[lo][lo] + h[lo + 1][lo + 1] - trace);
+    let mut z = h[lo + 1][lo] * h[lo + 2][lo + 1];
+
+    // chase the bulge down the subdiagonal
+    for k in lo..(hi - 1) {
+        let (v, _) = householder_vector(&[x, y, z]);
+        let from = if k > lo { k - 1 } else { lo };
+
+        reflect_rows(h, &v, k, from, n);
+        reflect_cols(h, &v, k, 0, (k + 4).min(hi + 1));
+        reflect_cols(q, &v, k, 0, n);
+
+        if k > lo {
+            h[k + 1][k - 1] = T::zero();
+            h[k + 2][k - 1] = T::zero();
+        }
+
+        x = h[k + 1][k];
+        y = h[k + 2][k];
+        if k + 3 <= hi {
+            z = h[k + 3][k];
+        }
+    }
+
+    let (v, _) = householder_vector(&[x, y]);
+    reflect_rows(h, &v, hi - 1, hi - 2, n);
+    reflect_cols(h, &v, hi - 1, 0, hi + 1);
+    reflect_cols(q, &v, hi - 1, 0, n);
+    h[hi][hi - 2] = T::zero();
+}
+
+fn standardise_block<T: Float>(h: &mut [Vec<T>], q: &mut [Vec<T>], p: usize) {
+    // a 2 x 2 block at rows p, p + 1 with real eigenvalues is rotated to upper triangular,
+    // complex pairs are left as they are
+    let two = T::one() + T::one();
+    let half = (h[p][p] - h[p + 1][p + 1]) / two;
+    let disc = half * half + h[p][p + 1] * h[p + 1][p];
+
+    if disc < T::zero() {
+        return;
+    }
+
+    let root = disc.sqrt();
+    let z = if half < T::zero() {
+        half - root
+    } else {
+        half + root
+    };
+    let x = h[p + 1][p];
+    let scale = x.abs() + z.abs();
+    let (s, c) = (x / scale, z / scale);
+    let r = (s * s + c * c).sqrt();
+    let (s, c) = (s / r, c / r);
+
+    let (top, bottom) = h.split_at_mut(p + 1);
+    for (upper, lower) in top[p][p..].iter_mut().zip(bottom[0][p..].iter_mut()) {
+        let (a, b) = (*upper, *lower);
+        *upper = c * a + s * b;
+        *lower = c * b - s * a;
+    }
+    for row in h.iter_mut().take(p + 2) {
+        let (a, b) = (row[p], row[p + 1]);
+        row[p] = c * a + s * b;
+        row[p + 1] = c * b - s * a;
+    }
+    for row in q.iter_mut() {
+        let (a, b) = (row[p], row[p + 1]);
+        row[p] = c * a + s * b;
+        row[p + 1] = c * b - s * a;
+    }
+
+    h[p + 1][p] = T::zero();
+}
+
+fn block_eigenvalues<T: Float>(a: T, b: T, c: T, d: T) -> (T, T) {
+    // real part and non negative imaginary part of the eigenvalues of [[a, b], [c, d]]
+    // that standardise_block left in place
+    let two = T::one() + T::one();
+    let half = (a - d) / two;
+    let disc = half * half + b * c;
+
+    (d + half, (-disc).sqrt())
+}
+
+#[cfg(test)]
+#[path = "./_tests/eigen.rs"]
+mod tests;
@@ -0,0 +1,174 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MatrixError {
+    // an intermediate value no longer fits in the element type
+    Overflow,
+    // the matrix has no inverse
+    Singular,
+    // the graph has a cycle of negative total weight, so shortest paths are unbounded
+    NegativeCycle,
+    // a pivot of a cholesky style factorisation was not positive, numbered from 1 like Index
+    NotPositiveDefinite { pivot: usize },
+    // an iterative method ran out of iterations before converging
+    NoConvergence,
+}
+
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatrixError::Overflow => write!(f, "arithmetic overflow in intermediate result"),
+            MatrixError::Singular => write!(f, "matrix is singular"),
+            MatrixError::NegativeCycle => write!(f, "graph contains a negative cycle"),
+            MatrixError::NotPositiveDefinite { pivot } => {
+                write!(f, "matrix is not positive definite at pivot {}", pivot)
+            }
+            MatrixError::NoConvergence => write!(f, "iteration did not converge"),
+        }
+    }
+}
+
+impl std::error::Error for MatrixError {}
+
+#[derive(Debug)]
+pub enum ReadError {
+    // the underlying reader failed
+    Io(std::io::Error),
+    // the input does not follow the format, line and column are numbered from 1
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    // a binary input does not follow the format
+    Format(String),
+}
+
+impl ReadError {
+    pub(crate) fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        ReadError::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(err) => write!(f, "{}", err),
+            ReadError::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "line {}, column {}: {}", line, column, message),
+            ReadError::Format(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReadError::Io(err) => Some(err),
+            ReadError::Parse { .. } | ReadError::Format(_) => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ReadError {
+    fn from(err: std::io::Error) -> Self {
+        ReadError::Io(err)
+    }
+}
+
+#[derive(Debug)]
+pub enum BinaryError {
+    // the underlying reader or writer failed
+    Io(std::io::Error),
+    // the input ended inside the header, the data or the checksum
+    Truncated,
+    // the input does not start with the format's magic bytes
+    BadMagic,
+    UnsupportedVersion(u8),
+    // only little endian data is written, so anything but `<` is rejected
+    UnsupportedByteOrder(u8),
+    // the file holds a different element type, both are element tags
+    ElementMismatch { expected: u8, found: u8 },
+    // a dimension is zero or the data would not fit in memory
+    InvalidShape { rows: u64, cols: u64 },
+    ChecksumMismatch { expected: u32, found: u32 },
+}
+
+impl fmt::Display for BinaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BinaryError::Io(err) => write!(f, "{}", err),
+            BinaryError::Truncated => write!(f, "matrix file is truncated"),
+            BinaryError::BadMagic => write!(f, "not a matrix file, the magic bytes are wrong"),
+            BinaryError::UnsupportedVersion(version) => {
+                write!(f, "unsupported matrix file version {}", version)
+            }
+            BinaryError::UnsupportedByteOrder(order) => {
+                write!(f, "unsupported byte order marker {:#04x}", order)
+            }
+            BinaryError::ElementMismatch { expected, found } => write!(
+                f,
+                "expected {} elements, found {}",
+                crate::binary::element_name(*expected),
+                crate::binary::element_name(*found)
+            ),
+            BinaryError::InvalidShape { rows, cols } => {
+                write!(f, "invalid matrix shape {} x {}", rows, cols)
+            }
+            BinaryError::ChecksumMismatch { expected, found } => write!(
+                f,
+                "checksum mismatch, expected {:#010x}, found {:#010x}",
+                expected, found
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BinaryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BinaryError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for BinaryError {
+    fn from(err: std::io::Error) -> Self {
+        match err.kind() {
+            std::io::ErrorKind::UnexpectedEof => BinaryError::Truncated,
+            _ => BinaryError::Io(err),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseMatrixError {
+    // character position in the parsed text, numbered from 1
+    pub position: usize,
+    pub message: String,
+}
+
+impl ParseMatrixError {
+    pub(crate) fn new(position: usize, message: impl Into<String>) -> Self {
+        ParseMatrixError {
+            position,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseMatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "position {}: {}", self.position, self.message)
+    }
+}
+
+impl std::error::Error for ParseMatrixError {}
@@ -0,0 +1,183 @@
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+
+use crate::matrix::Matrix;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LatexEnv {
+    Pmatrix,
+    Bmatrix,
+    // a bare array, every column gets the same alignment
+    Array(Align),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Highlight {
+    // 1 based [row, col] pairs, like indexing
+    pub cells: BTreeSet<[usize; 2]>,
+    // a colour name understood by both xcolor and css
+    pub color: String,
+}
+
+impl Default for Highlight {
+    fn default() -> Self {
+        Highlight {
+            cells: BTreeSet::new(),
+            color: "yellow".to_string(),
+        }
+    }
+}
+
+impl Highlight {
+    pub fn block(rows: RangeInclusive<usize>, cols: RangeInclusive<usize>) -> Self {
+        let cells = rows
+            .flat_map(|r| cols.clone().map(move |c| [r, c]))
+            .collect();
+
+        Highlight {
+            cells,
+            ..Highlight::default()
+        }
+    }
+
+    pub fn tile(row: usize, col: usize) -> Self {
+        // the 2x2 tile strass multiplies at [row, col] of its Blocks, [1, 1] is the top
+        // left. strass pads odd sizes with zeros, cells of a tile in that padding are
+        // never drawn
+        if row == 0 || col == 0 {
+            panic!("tiles are indexed from 1, found [{}, {}]", row, col)
+        }
+
+        Highlight::block(2 * row - 1..=2 * row, 2 * col - 1..=2 * col)
+    }
+
+    fn contains(&self, row: usize, col: usize) -> bool {
+        self.cells.contains(&[row + 1, col + 1])
+    }
+}
+
+impl<T: Display> Matrix<T> {
+    // export
+
+    pub fn to_latex(&self, env: LatexEnv) -> String {
+        self.to_latex_highlighted(env, &Highlight::default())
+    }
+
+    pub fn to_latex_highlighted(&self, env: LatexEnv, highlight: &Highlight) -> String {
+        // highlighted cells use \colorbox, which needs the xcolor package
+        let mut out = match env {
+            LatexEnv::Pmatrix => "\\begin{pmatrix}\n".to_string(),
+            LatexEnv::Bmatrix => "\\begin{bmatrix}\n".to_string(),
+            LatexEnv::Array(align) => {
+                let spec = match align {
+                    Align::Left => "l",
+                    Align::Center => "c",
+                    Align::Right => "r",
+                };
+                format!("\\begin{{array}}{{{}}}\n", spec.repeat(self.cols()))
+            }
+        };
+
+        for (r, row) in self.cells.iter().enumerate() {
+            let line: Vec<String> = row
+                .iter()
+                .enumerate()
+                .map(|(c, cell)| {
+                    if highlight.contains(r, c) {
+                        format!("\\colorbox{{{}}}{{${}$}}", highlight.color, cell)
+                    } else {
+                        cell.to_string()
+                    }
+                })
+                .collect();
+
+            out.push_str(&line.join(" & "));
+            out.push_str(if r + 1 < self.rows() { " \\\\\n" } else { "\n" });
+        }
+
+        out.push_str(match env {
+            LatexEnv::Pmatrix => "\\end{pmatrix}\n",
+            LatexEnv::Bmatrix => "\\end{bmatrix}\n",
+            LatexEnv::Array(_) => "\\end{array}\n",
+        });
+
+        out
+    }
+
+    pub fn to_markdown_table(&self) -> String {
+        self.to_markdown_table_highlighted(&Highlight::default())
+    }
+
+    pub fn to_markdown_table_highlighted(&self, highlight: &Highlight) -> String {
+        // markdown has no colours, highlighted cells are bold. tables need a header, so
+        // the columns are numbered, and numbers are right aligned
+        let header: Vec<String> = (1..=self.cols()).map(|c| c.to_string()).collect();
+        let mut out = format!("| {} |\n", header.join(" | "));
+        out.push_str(&format!("|{}\n", "---:|".repeat(self.cols())));
+
+        for (r, row) in self.cells.iter().enumerate() {
+            let line: Vec<String> = row
+                .iter()
+                .enumerate()
+                .map(|(c, cell)| {
+                    if highlight.contains(r, c) {
+                        format!("**{}**", cell)
+                    } else {
+                        cell.to_string()
+                    }
+                })
+                .collect();
+
+            out.push_str(&format!("| {} |\n", line.join(" | ")));
+        }
+
+        out
+    }
+
+    pub fn to_html_table(&self) -> String {
+        self.to_html_table_highlighted(&Highlight::default())
+    }
+
+    pub fn to_html_table_highlighted(&self, highlight: &Highlight) -> String {
+        let mut out = "<table>\n".to_string();
+
+        for (r, row) in self.cells.iter().enumerate() {
+            out.push_str("  <tr>");
+            for (c, cell) in row.iter().enumerate() {
+                let text = escape_html(&cell.to_string());
+                if highlight.contains(r, c) {
+                    out.push_str(&format!(
+                        "<td style=\"background-color: {}\">{}</td>",
+                        escape_html(&highlight.color),
+                        text
+                    ));
+                } else {
+                    out.push_str(&format!("<td>{}</td>", text));
+                }
+            }
+            out.push_str("</tr>\n");
+        }
+
+        out.push_str("</table>\n");
+        out
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+#[path = "./_tests/export.rs"]
+mod tests;
@@ -0,0 +1,157 @@
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+use crate::semiring::{MinPlus, Semiring};
+
+// weight used for a missing edge in the shortest path methods, same as MinPlus
+pub const NO_EDGE: isize = isize::MAX;
+
+impl Matrix {
+    // graph algorithms, the matrix is read as an adjacency matrix where entry [i, j]
+    // is the edge (or number of edges) from vertex i to vertex j
+
+    pub fn walk_counts(&self, length: u32) -> Matrix {
+        // entry [i, j] of A^k counts the walks of length k from i to j
+        self.assert_square("Walk counting");
+
+        self.pow(length)
+    }
+
+    pub fn transitive_closure(&self) -> Matrix<bool> {
+        // warshall, entry [i, j] is true when j can be reached from i in one or more steps
+        let n = self.assert_square("Transitive closure");
+        let mut reach = self.map(|cell| cell != 0);
+
+        for k in 0..n {
+            for i in 0..n {
+                if !reach.cells[i][k] {
+                    continue;
+                }
+
+                for j in 0..n {
+                    reach.cells[i][j] = reach.cells[i][j] || reach.cells[k][j];
+                }
+            }
+        }
+
+        reach
+    }
+
+    pub fn floyd_warshall(&self) -> Result<Matrix, MatrixError> {
+        // all pairs shortest paths, missing edges and unreachable pairs are NO_EDGE
+        let n = self.assert_square("Shortest paths");
+        let mut dist = self.with_zero_diagonal();
+
+        for k in 0..n {
+            for i in 0..n {
+                for j in 0..n {
+                    let through = MinPlus::combine(dist.cells[i][k], dist.cells[k][j]);
+                    dist.cells[i][j] = MinPlus::accumulate(dist.cells[i][j], through);
+                }
+            }
+        }
+
+        if (0..n).any(|i| dist.cells[i][i] < 0) {
+            return Err(MatrixError::NegativeCycle);
+        }
+
+        Ok(dist)
+    }
+
+    pub fn shortest_paths_squaring(&self) -> Result<Matrix, MatrixError> {
+        // all pairs shortest paths by min-plus squaring, after squaring s times the
+        // matrix holds the shortest paths using at most 2^s edges
+        let n = self.assert_square("Shortest paths");
+        let mut dist = self.with_zero_diagonal();
+        let mut edges = 1;
+
+        while edges < n {
+            dist = dist.semiring_mul::<MinPlus>(&dist);
+            edges *= 2;
+        }
+
+        // one more squaring only changes anything if a negative cycle exists
+        if dist.semiring_mul::<MinPlus>(&dist) != dist {
+            return Err(MatrixError::NegativeCycle);
+        }
+
+        Ok(dist)
+    }
+
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        // components of the undirected graph, edges are followed in both directions,
+        // vertices are 1 indexed to match Index
+        let n = self.assert_square("Connected components");
+        let mut seen = vec![false; n];
+        let mut components = vec![];
+
+        for start in 0..n {
+            if seen[start] {
+                continue;
+            }
+
+            seen[start] = true;
+            let mut stack = vec![start];
+            let mut component = vec![];
+
+            while let Some(v) = stack.pop() {
+                component.push(v + 1);
+
+                for (u, seen_u) in seen.iter_mut().enumerate() {
+                    if !*seen_u && (self.cells[v][u] != 0 || self.cells[u][v] != 0) {
+                        *seen_u = true;
+                        stack.push(u);
+                    }
+                }
+            }
+
+            component.sort();
+            components.push(component);
+        }
+
+        components
+    }
+
+    pub fn spanning_tree_count(&self) -> Result<isize, MatrixError> {
+        // kirchhoff's matrix tree theorem, any cofactor of the laplacian D - A,
+        // the matrix is read as an undirected multigraph and self loops are ignored
+        let n = self.assert_square("Spanning tree counting");
+
+        if n == 1 {
+            return Ok(1);
+        }
+
+        let mut laplacian = Matrix::zeroes(n - 1, n - 1);
+
+        for i in 1..n {
+            for j in 1..n {
+                laplacian.cells[i - 1][j - 1] = if i == j {
+                    (0..n)
+                        .filter(|&k| k != i)
+                        .try_fold(0isize, |acc, k| acc.checked_add(self.cells[i][k]))
+                        .ok_or(MatrixError::Overflow)?
+                } else {
+                    self.cells[i][j]
+                        .checked_neg()
+                        .ok_or(MatrixError::Overflow)?
+                };
+            }
+        }
+
+        laplacian.determinant()
+    }
+
+    fn with_zero_diagonal(&self) -> Matrix {
+        // a vertex is always at distance zero from itself unless a negative loop says otherwise
+        let mut out = self.clone();
+
+        for (idx, row) in out.cells.iter_mut().enumerate() {
+            row[idx] = row[idx].min(0);
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+#[path = "./_tests/graph.rs"]
+mod tests;
@@ -0,0 +1,298 @@
+use std::ops::Mul;
+
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+use crate::rational::{gcd, Rational};
+use crate::scalar::{Field, Scalar};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RationalMatrix {
+    // exact rational matrix stored as integer numerators over a shared denominator,
+    // kept normalised so that den > 0 and gcd(num, den) == 1
+    num: Matrix,
+    den: isize,
+}
+
+impl RationalMatrix {
+    pub fn new(num: Matrix, den: isize) -> Result<Self, MatrixError> {
+        if den == 0 {
+            panic!("denominator of a RationalMatrix must be non zero")
+        }
+
+        let mut out = RationalMatrix { num, den };
+        out.normalise()?;
+
+        Ok(out)
+    }
+
+    pub fn numerator(&self) -> &Matrix {
+        &self.num
+    }
+
+    pub fn denominator(&self) -> isize {
+        self.den
+    }
+
+    pub fn to_matrix(&self) -> Option<Matrix> {
+        // only succeeds when every entry is an integer
+        if self.den == 1 {
+            Some(self.num.clone())
+        } else {
+            None
+        }
+    }
+
+    fn normalise(&mut self) -> Result<(), MatrixError> {
+        // done in i128 so that a gcd of 2^63 can still be negated, a result that does
+        // not fit back in isize (isize::MIN / -1) is an overflow
+        let g = self
+            .num
+            .cells
+            .iter()
+            .flatten()
+            .fold(self.den as i128, |acc, &cell| gcd(acc, cell as i128));
+
+        let g = if self.den < 0 { -g } else { g };
+
+        for cell in self.num.cells.iter_mut().flatten() {
+            *cell = (*cell as i128 / g)
+                .try_into()
+                .map_err(|_| MatrixError::Overflow)?;
+        }
+        self.den = (self.den as i128 / g)
+            .try_into()
+            .map_err(|_| MatrixError::Overflow)?;
+
+        Ok(())
+    }
+
+    fn with_positive_den(num: Matrix, den: isize) -> Self {
+        // the gcd divides den > 0, so it fits in isize and dividing by it cannot
+        // overflow, unlike negating for a negative denominator in normalise
+        let g = num
+            .cells
+            .iter()
+            .flatten()
+            .fold(den as i128, |acc, &cell| gcd(acc, cell as i128)) as isize;
+
+        let mut out = RationalMatrix { num, den: den / g };
+        for cell in out.num.cells.iter_mut().flatten() {
+            *cell /= g;
+        }
+
+        out
+    }
+}
+
+impl Matrix {
+    // inverses
+
+    pub fn inverse(&self) -> Result<RationalMatrix, MatrixError> {
+        // fraction free (bareiss) gauss-jordan on [A | I], the returned matrix
+        // is adj(A) / det(A) in lowest terms
+        let n = self.assert_square("Inversion");
+        let mut aug = augment(self);
+        let mut prev = 1isize;
+
+        for k in 0..n {
+            let pivot = (k..n)
+                .find(|&r| aug[r][k] != 0)
+                .ok_or(MatrixError::Singular)?;
+            aug.swap(k, pivot);
+
+            for i in (0..n).filter(|&i| i != k) {
+                for j in (0..2 * n).filter(|&j| j != k) {
+                    let lhs = aug[k][k].checked_mul(aug[i][j]);
+                    let rhs = aug[i][k].checked_mul(aug[k][j]);
+
+                    // bareiss guarantees the division by the previous pivot is exact
+                    aug[i][j] = lhs
+                        .zip(rhs)
+                        .and_then(|(lhs, rhs)| lhs.checked_sub(rhs))
+                        .and_then(|x| x.checked_div(prev))
+                        .ok_or(MatrixError::Overflow)?;
+                }
+
+                aug[i][k] = 0;
+            }
+
+            prev = aug[k][k];
+        }
+
+        // the left half is now prev * I, so the right half is prev * A^-1
+        let num = aug.into_iter().map(|row| row[n..].to_vec()).collect();
+
+        RationalMatrix::new(Matrix::new(num), prev)
+    }
+
+    pub fn inverse_mod(&self, p: isize) -> Result<Matrix, MatrixError> {
+        // gauss-jordan over Z/pZ, the pivot in each column is built up with
+        // euclid so composite moduli work as well as primes
+        let n = self.assert_square("Inversion");
+
+        if p < 2 {
+            panic!("modulus must be at least 2, found {}", p)
+        }
+
+        let mut aug = augment(self);
+
+        for cell in aug.iter_mut().flatten() {
+            *cell = cell.rem_euclid(p);
+        }
+
+        for k in 0..n {
+            loop {
+                let smallest = (k..n)
+                    .filter(|&r| aug[r][k] != 0)
+                    .min_by_key(|&r| aug[r][k])
+                    .ok_or(MatrixError::Singular)?;
+                aug.swap(k, smallest);
+
+                let mut cleared = true;
+
+                for r in (k + 1)..n {
+                    let q = aug[r][k] / aug[k][k];
+                    mod_row_sub(&mut aug, r, k, q, p);
+
+                    if aug[r][k] != 0 {
+                        cleared = false;
+                    }
+                }
+
+                if cleared {
+                    break;
+                }
+            }
+
+            let pivot_inv = mod_inverse(aug[k][k], p).ok_or(MatrixError::Singular)?;
+
+            for cell in aug[k].iter_mut() {
+                *cell = mod_mul(*cell, pivot_inv, p);
+            }
+
+            for r in (0..n).filter(|&r| r != k) {
+                let q = aug[r][k];
+                mod_row_sub(&mut aug, r, k, q, p);
+            }
+        }
+
+        let out = aug.into_iter().map(|row| row[n..].to_vec()).collect();
+
+        Ok(Matrix::new(out))
+    }
+}
+
+impl<T: Field> Matrix<T> {
+    pub fn inverse(&self) -> Result<Matrix<T>, MatrixError> {
+        // plain gauss-jordan on [A | I], fine for exact fields
+        let n = self.assert_square("Inversion");
+        let mut aug = augment(self);
+
+        for k in 0..n {
+            let pivot = (k..n)
+                .find(|&r| aug[r][k] != T::zero())
+                .ok_or(MatrixError::Singular)?;
+            aug.swap(k, pivot);
+
+            let pivot = aug[k][k];
+            for cell in aug[k].iter_mut() {
+                *cell = *cell / pivot;
+            }
+
+            let pivot_row = aug[k].clone();
+
+            for (r, row) in aug.iter_mut().enumerate() {
+                if r == k {
+                    continue;
+                }
+
+                let factor = row[k];
+
+                for (cell, &p) in row.iter_mut().zip(pivot_row.iter()) {
+                    *cell = *cell - factor * p;
+                }
+            }
+        }
+
+        let out = aug.into_iter().map(|row| row[n..].to_vec()).collect();
+
+        Ok(Matrix::new(out))
+    }
+}
+
+impl Mul<RationalMatrix> for Matrix {
+    type Output = RationalMatrix;
+
+    fn mul(self, b: RationalMatrix) -> Self::Output {
+        let num = self * b.num;
+
+        RationalMatrix::with_positive_den(num, b.den)
+    }
+}
+
+impl Mul<Matrix> for RationalMatrix {
+    type Output = RationalMatrix;
+
+    fn mul(self, b: Matrix) -> Self::Output {
+        let num = self.num * b;
+
+        RationalMatrix::with_positive_den(num, self.den)
+    }
+}
+
+impl PartialEq<Matrix> for RationalMatrix {
+    fn eq(&self, other: &Matrix) -> bool {
+        self.den == 1 && self.num == *other
+    }
+}
+
+impl From<RationalMatrix> for Matrix<Rational> {
+    fn from(mat: RationalMatrix) -> Self {
+        mat.num.map(|cell| Rational::new(cell, mat.den))
+    }
+}
+
+fn augment<T: Scalar>(mat: &Matrix<T>) -> Vec<Vec<T>> {
+    // builds [A | I]
+    let ident = Matrix::<T>::identity(mat.rows());
+
+    mat.cells
+        .iter()
+        .zip(ident.cells)
+        .map(|(row, ident_row)| row.iter().copied().chain(ident_row).collect())
+        .collect()
+}
+
+fn mod_mul(a: isize, b: isize, p: isize) -> isize {
+    (a as i128 * b as i128).rem_euclid(p as i128) as isize
+}
+
+fn mod_row_sub(cells: &mut [Vec<isize>], target: usize, source: usize, q: isize, p: isize) {
+    // row[target] -= q * row[source] (mod p)
+    for c in 0..cells[target].len() {
+        let x = mod_mul(q, cells[source][c], p);
+        cells[target][c] = (cells[target][c] - x).rem_euclid(p);
+    }
+}
+
+fn mod_inverse(a: isize, p: isize) -> Option<isize> {
+    // extended euclid, None if a is not a unit mod p
+    let (mut old_r, mut r) = (a, p);
+    let (mut old_s, mut s) = (1isize, 0isize);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+    }
+
+    if old_r == 1 {
+        Some(old_s.rem_euclid(p))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+#[path = "./_tests/inverse.rs"]
+mod tests;
@@ -1 +1,30 @@
+pub mod approx;
+pub mod binary;
+pub mod bit_matrix;
+pub mod cholesky;
+pub mod csv;
+pub mod determinant;
+pub mod display;
+pub mod eigen;
+pub mod error;
+pub mod export;
+pub mod graph;
+pub mod inverse;
+pub mod lu;
+pub mod mapped;
 pub mod matrix;
+pub mod matrix_market;
+pub mod normal_form;
+pub mod npy;
+pub mod parse;
+pub mod pnm;
+pub mod polynomial;
+pub mod qr;
+pub mod rational;
+pub mod scalar;
+pub mod semiring;
+#[cfg(feature = "serde")]
+pub mod serialize;
+pub mod sparse;
+pub mod svd;
+pub mod zp;
@@ -0,0 +1,321 @@
+use std::cmp::Ordering;
+
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+use crate::scalar::Float;
+
+// panels at most this wide are factored column by column
+const LU_BLOCK: usize = 16;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Lu<T> {
+    // P * A = L * U with L unit lower triangular and U upper triangular, both packed
+    // into one matrix, perm[i] is the row of A that ended up in row i
+    factors: Matrix<T>,
+    perm: Vec<usize>,
+    swaps: usize,
+    // largest entry of each row of A, check_pivots judges a pivot against its row of A
+    // as well as its column of U so scaling either is not mistaken for singularity
+    scale: Vec<T>,
+}
+
+impl<T: Float> Matrix<T> {
+    // lu decomposition
+
+    pub fn lu(&self) -> Result<Lu<T>, MatrixError> {
+        // recursive blocked lu with partial pivoting, the left half of each panel is
+        // factored first, the right half is updated with strass and then factored
+        let n = self.assert_square("LU decomposition");
+
+        // only an exactly zero pivot stops the factorisation, solve and inverse apply
+        // a tolerance on top through check_pivots
+        let scale = self
+            .cells
+            .iter()
+            .map(|row| {
+                row.iter().fold(
+                    T::zero(),
+                    |acc, &cell| {
+                        if cell.abs() > acc {
+                            cell.abs()
+                        } else {
+                            acc
+                        }
+                    },
+                )
+            })
+            .collect();
+
+        let mut lu = Lu {
+            factors: self.clone(),
+            perm: (0..n).collect(),
+            swaps: 0,
+            scale,
+        };
+
+        lu.factor(0, n)?;
+
+        Ok(lu)
+    }
+
+    pub fn solve(&self, b: &Self) -> Result<Self, MatrixError> {
+        // solves A X = B, b can be a single column or several
+        self.lu()?.solve(b)
+    }
+}
+
+impl<T: Float> Lu<T> {
+    pub fn l(&self) -> Matrix<T> {
+        let n = self.factors.rows();
+        let mut out = Matrix::identity(n);
+
+        for r in 0..n {
+            out.cells[r][..r].copy_from_slice(&self.factors.cells[r][..r]);
+        }
+
+        out
+    }
+
+    pub fn u(&self) -> Matrix<T> {
+        let n = self.factors.rows();
+        let mut out = Matrix::zeroes(n, n);
+
+        for r in 0..n {
+            out.cells[r][r..].copy_from_slice(&self.factors.cells[r][r..]);
+        }
+
+        out
+    }
+
+    pub fn p(&self) -> Matrix<T> {
+        let n = self.factors.rows();
+        let mut out = Matrix::zeroes(n, n);
+
+        for (r, &c) in self.perm.iter().enumerate() {
+            out.cells[r][c] = T::one();
+        }
+
+        out
+    }
+
+    pub fn permutation(&self) -> &[usize] {
+        &self.perm
+    }
+
+    pub fn solve(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        // a pivot lost in rounding error gives Singular rather than inf or NaN
+        let n = self.factors.rows();
+
+        if b.rows() != n {
+            panic!(
+                "Right hand side must have {} rows to solve. Found {} x {}",
+                n,
+                b.rows(),
+                b.cols()
+            )
+        }
+
+        self.check_pivots()?;
+
+        let lu = &self.factors.cells;
+        let mut x: Vec<Vec<T>> = self.perm.iter().map(|&r| b.cells[r].clone()).collect();
+
+        forward_substitute(&mut x, true, |i, k| lu[i][k]);
+        back_substitute(&mut x, |i, k| lu[i][k]);
+
+        Ok(Matrix::new(x))
+    }
+
+    pub fn determinant(&self) -> T {
+        let det = (0..self.factors.rows()).fold(T::one(), |acc, i| acc * self.factors.cells[i][i]);
+
+        if self.swaps.is_multiple_of(2) {
+            det
+        } else {
+            -det
+        }
+    }
+
+    pub fn inverse(&self) -> Result<Matrix<T>, MatrixError> {
+        self.solve(&Matrix::identity(self.factors.rows()))
+    }
+
+    fn check_pivots(&self) -> Result<(), MatrixError> {
+        // the rounding error in u_kk is bounded both by the largest entry of its row of A
+        // and by the largest entry of its column of U, since |l| <= 1. a pivot within
+        // the smaller of the two means A is numerically singular
+        let n = self.factors.rows();
+        let eps = T::epsilon() * T::from_f64(n as f64);
+        let u = &self.factors.cells;
+
+        for k in 0..n {
+            let column = u[..=k].iter().fold(T::zero(), |acc, row| {
+                if row[k].abs() > acc {
+                    row[k].abs()
+                } else {
+                    acc
+                }
+            });
+            let row = self.scale[self.perm[k]];
+            let tol = if row < column { row } else { column } * eps;
+
+            if u[k][k].abs().partial_cmp(&tol) != Some(Ordering::Greater) {
+                return Err(MatrixError::Singular);
+            }
+        }
+
+        Ok(())
+    }
+
+    // rows i and k of the same matrix are read and written together
+    #[allow(clippy::needless_range_loop)]
+    fn factor(&mut self, c0: usize, c1: usize) -> Result<(), MatrixError> {
+        // factors the panel of columns c0..c1 below row c0, rows are always swapped
+        // in full so earlier columns of L follow their row
+        if c1 - c0 <= LU_BLOCK {
+            return self.factor_unblocked(c0, c1);
+        }
+
+        let mid = c0 + (c1 - c0) / 2;
+        self.factor(c0, mid)?;
+
+        let n = self.factors.rows();
+        let a = &mut self.factors.cells;
+
+        // A12 = L11^-1 A12
+        for i in c0..mid {
+            for k in c0..i {
+                let l = a[i][k];
+                for c in mid..c1 {
+                    a[i][c] = a[i][c] - l * a[k][c];
+                }
+            }
+        }
+
+        // A22 -= L21 A12 with the fast multiplication
+        let l21 = Matrix::new((mid..n).map(|r| a[r][c0..mid].to_vec()).collect());
+        let a12 = Matrix::new((c0..mid).map(|r| a[r][mid..c1].to_vec()).collect());
+        let update = l21.strass(&a12);
+
+        for (r, row) in update.cells.iter().enumerate() {
+            for (c, &cell) in row.iter().enumerate() {
+                a[mid + r][mid + c] = a[mid + r][mid + c] - cell;
+            }
+        }
+
+        self.factor(mid, c1)
+    }
+
+    // rows i and k of the same matrix are read and written together
+    #[allow(clippy::needless_range_loop)]
+    fn factor_unblocked(&mut self, c0: usize, c1: usize) -> Result<(), MatrixError> {
+        let n = self.factors.rows();
+        let a = &mut self.factors.cells;
+
+        for k in c0..c1 {
+            // NaN counts as the largest so it is carried through rather than passed over
+            // for a zero pivot
+            let pivot = (k..n)
+                .max_by(|&x, &y| {
+                    let (x, y) = (a[x][k].abs(), a[y][k].abs());
+                    x.partial_cmp(&y)
+                        .unwrap_or_else(|| x.is_nan().cmp(&y.is_nan()))
+                })
+                .unwrap();
+
+            if a[pivot][k] == T::zero() {
+                return Err(MatrixError::Singular);
+            }
+
+            if pivot != k {
+                a.swap(k, pivot);
+                self.perm.swap(k, pivot);
+                self.swaps += 1;
+            }
+
+            for r in (k + 1)..n {
+                let l = a[r][k] / a[k][k];
+                a[r][k] = l;
+
+                for c in (k + 1)..c1 {
+                    a[r][c] = a[r][c] - l * a[k][c];
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) fn forward_substitute<T: Float>(
+    x: &mut [Vec<T>],
+    unit_diagonal: bool,
+    l: impl Fn(usize, usize) -> T,
+) {
+    // solves L Y = X in place, column by column of X. l(i, k) is read for k <= i, the
+    // diagonal only when it is not taken to be one
+    for i in 0..x.len() {
+        for k in 0..i {
+            let (done, rest) = x.split_at_mut(i);
+            let lik = l(i, k);
+            for (cell, &xk) in rest[0].iter_mut().zip(done[k].iter()) {
+                *cell = *cell - lik * xk;
+            }
+        }
+
+        if !unit_diagonal {
+            let lii = l(i, i);
+            for cell in x[i].iter_mut() {
+                *cell = *cell / lii;
+            }
+        }
+    }
+}
+
+pub(crate) fn back_substitute<T: Float>(x: &mut [Vec<T>], u: impl Fn(usize, usize) -> T) {
+    // solves U Y = X in place, u(i, k) is read for k >= i
+    let n = x.len();
+
+    for i in (0..n).rev() {
+        for k in (i + 1)..n {
+            let (head, tail) = x.split_at_mut(k);
+            let uik = u(i, k);
+            for (cell, &xk) in head[i].iter_mut().zip(tail[0].iter()) {
+                *cell = *cell - uik * xk;
+            }
+        }
+
+        let uii = u(i, i);
+        for cell in x[i].iter_mut() {
+            *cell = *cell / uii;
+        }
+    }
+}
+
+macro_rules! impl_float_lu_ops {
+    ($($t:ty),*) => {
+        $(
+            // kept off the generic impl since Field already provides exact versions
+            impl Matrix<$t> {
+                pub fn determinant(&self) -> $t {
+                    // lu only gives up on an exactly zero pivot, so 0 is the true value.
+                    // NaN pivots are kept and come out as a NaN determinant
+                    match self.lu() {
+                        Ok(lu) => lu.determinant(),
+                        Err(_) => 0.0,
+                    }
+                }
+
+                pub fn inverse(&self) -> Result<Matrix<$t>, MatrixError> {
+                    self.lu()?.inverse()
+                }
+            }
+        )*
+    };
+}
+
+impl_float_lu_ops!(f32, f64);
+
+#[cfg(test)]
+#[path = "./_tests/lu.rs"]
+mod tests;
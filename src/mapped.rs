@@ -0,0 +1,329 @@
+use std::fs::{File, OpenOptions};
+use std::io;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+
+use memmap2::{Mmap, MmapMut};
+
+use crate::binary::{BinaryElement, Header, CHECKSUM_LEN, HEADER_LEN};
+use crate::error::BinaryError;
+use crate::matrix::Matrix;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Kernel {
+    // the plain triple loop of Mul on each pair of tiles
+    Blocked,
+    // Matrix::strass on each pair of tiles
+    Strassen,
+}
+
+enum Map {
+    ReadOnly(Mmap),
+    Writable(MmapMut),
+}
+
+pub struct MappedMatrix<T> {
+    // a file in the binary format mapped into memory, only the pages that are touched
+    // are read, so the matrix can be much larger than RAM
+    map: Map,
+    // canonical, so multiply_to can refuse to overwrite an input by name
+    path: PathBuf,
+    rows: usize,
+    cols: usize,
+    // set by writes, the checksum is rewritten on flush or drop
+    dirty: bool,
+    element: PhantomData<T>,
+}
+
+impl<T: BinaryElement> MappedMatrix<T> {
+    /// Maps an existing file read only, the checksum is verified up front with one pass
+    /// over the file.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated, by this or any other process, for as
+    /// long as the returned matrix is alive. Reads go through slices of the map that
+    /// assume the bytes do not change underneath them, and a truncated file faults on
+    /// access.
+    pub unsafe fn open<P: AsRef<Path>>(path: P) -> Result<Self, BinaryError> {
+        let file = File::open(&path)?;
+        let path = path.as_ref().canonicalize()?;
+        // SAFETY: upheld by the caller
+        let map = unsafe { Mmap::map(&file)? };
+
+        let header: &[u8; HEADER_LEN] = map
+            .get(..HEADER_LEN)
+            .ok_or(BinaryError::Truncated)?
+            .try_into()
+            .unwrap();
+        let header = Header::decode::<T>(header)?;
+
+        let len = HEADER_LEN + header.rows * header.cols * T::SIZE;
+        let checksum = map
+            .get(len..len + CHECKSUM_LEN)
+            .ok_or(BinaryError::Truncated)?;
+        let expected = u32::from_le_bytes(checksum.try_into().unwrap());
+        let found = crc32fast::hash(&map[..len]);
+        if expected != found {
+            return Err(BinaryError::ChecksumMismatch { expected, found });
+        }
+
+        Ok(MappedMatrix {
+            map: Map::ReadOnly(map),
+            path,
+            rows: header.rows,
+            cols: header.cols,
+            dirty: false,
+            element: PhantomData,
+        })
+    }
+
+    /// Creates a rows x cols zero matrix at `path`, sized up front and filled in through
+    /// `set` and `write_tile`. An existing file is truncated.
+    ///
+    /// # Safety
+    ///
+    /// As for [`MappedMatrix::open`], the file must not be modified or truncated by
+    /// anything else while the returned matrix is alive. It must also not be mapped
+    /// anywhere when this is called, through this path or any other, since truncating
+    /// it pulls the pages out from under that map.
+    pub unsafe fn create<P: AsRef<Path>>(
+        path: P,
+        rows: usize,
+        cols: usize,
+    ) -> Result<Self, BinaryError> {
+        if rows == 0 || cols == 0 {
+            return Err(BinaryError::InvalidShape {
+                rows: rows as u64,
+                cols: cols as u64,
+            });
+        }
+
+        let len = rows
+            .checked_mul(cols)
+            .and_then(|cells| cells.checked_mul(T::SIZE))
+            .and_then(|data| data.checked_add(HEADER_LEN + CHECKSUM_LEN))
+            .ok_or(BinaryError::InvalidShape {
+                rows: rows as u64,
+                cols: cols as u64,
+            })?;
+
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&path)?;
+        file.set_len(len as u64)?;
+        let path = path.as_ref().canonicalize()?;
+        // SAFETY: upheld by the caller, writes only go through slices of the map
+        let mut map = unsafe { MmapMut::map_mut(&file)? };
+        map[..HEADER_LEN].copy_from_slice(&Header::new::<T>(rows, cols).encode());
+
+        Ok(MappedMatrix {
+            map: Map::Writable(map),
+            path,
+            rows,
+            cols,
+            dirty: true,
+            element: PhantomData,
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, index: [usize; 2]) -> T {
+        let at = self.offset(index[0], index[1]);
+
+        T::read_le(&self.bytes()[at..at + T::SIZE])
+    }
+
+    pub fn set(&mut self, index: [usize; 2], value: T) {
+        let at = self.offset(index[0], index[1]);
+
+        let mut buf = Vec::with_capacity(T::SIZE);
+        value.write_le(&mut buf);
+        self.bytes_mut()[at..at + T::SIZE].copy_from_slice(&buf);
+    }
+
+    pub fn read_tile(&self, row: usize, col: usize, rows: usize, cols: usize) -> Matrix<T> {
+        // the rows x cols tile whose top left cell is [row, col], numbered from 1
+        self.assert_tile(row, col, rows, cols);
+
+        let cells = (row..row + rows)
+            .map(|r| {
+                let at = self.offset(r, col);
+                self.bytes()[at..at + cols * T::SIZE]
+                    .chunks_exact(T::SIZE)
+                    .map(T::read_le)
+                    .collect()
+            })
+            .collect();
+
+        Matrix::new(cells)
+    }
+
+    pub fn write_tile(&mut self, row: usize, col: usize, tile: &Matrix<T>) {
+        self.assert_tile(row, col, tile.rows(), tile.cols());
+
+        let mut buf = Vec::with_capacity(tile.cols() * T::SIZE);
+        for (r, cells) in tile.cells.iter().enumerate() {
+            buf.clear();
+            for &cell in cells.iter() {
+                cell.write_le(&mut buf);
+            }
+
+            let at = self.offset(row + r, col);
+            self.bytes_mut()[at..at + buf.len()].copy_from_slice(&buf);
+        }
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T> {
+        self.read_tile(1, 1, self.rows, self.cols)
+    }
+
+    pub fn flush(&mut self) -> Result<(), BinaryError> {
+        // rewrites the checksum and syncs the file, a no op for read only maps
+        if let Map::Writable(map) = &mut self.map {
+            if self.dirty {
+                write_checksum(map);
+                self.dirty = false;
+            }
+            map.flush()?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the product of `self` and `b` to a new file at `path`, out of core. Only
+    /// three tile x tile matrices are held in memory at a time and each finished tile of
+    /// the result is written straight to the file.
+    ///
+    /// # Safety
+    ///
+    /// The same as [`MappedMatrix::create`] for `path`. Naming the file either input was
+    /// opened from is refused with an `InvalidInput` error, but only when the path
+    /// resolves to the same name, another hard link to it is not caught.
+    pub unsafe fn multiply_to<P: AsRef<Path>>(
+        &self,
+        b: &Self,
+        path: P,
+        tile: usize,
+        kernel: Kernel,
+    ) -> Result<Self, BinaryError> {
+        if self.cols != b.rows {
+            panic!(
+                "tiled multiplication needs r x m and m x c matrices, found {} x {} and {} x {}",
+                self.rows, self.cols, b.rows, b.cols
+            )
+        }
+        if tile == 0 {
+            panic!("tile size must be at least 1")
+        }
+
+        // the easy way to break create's contract, a path that does not exist yet cannot
+        // be an input
+        if let Ok(out) = path.as_ref().canonicalize() {
+            if out == self.path || out == b.path {
+                return Err(BinaryError::Io(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "cannot write the product to {}, an input is mapped from it",
+                        out.display()
+                    ),
+                )));
+            }
+        }
+
+        // SAFETY: upheld by the caller
+        let mut out = unsafe { MappedMatrix::create(path, self.rows, b.cols)? };
+
+        for row in (1..=self.rows).step_by(tile) {
+            let rows = tile.min(self.rows - row + 1);
+
+            for col in (1..=b.cols).step_by(tile) {
+                let cols = tile.min(b.cols - col + 1);
+                let mut acc = Matrix::zeroes(rows, cols);
+
+                for inner in (1..=self.cols).step_by(tile) {
+                    let depth = tile.min(self.cols - inner + 1);
+                    let a_tile = self.read_tile(row, inner, rows, depth);
+                    let b_tile = b.read_tile(inner, col, depth, cols);
+
+                    acc += match kernel {
+                        Kernel::Blocked => a_tile * b_tile,
+                        Kernel::Strassen => a_tile.strass(&b_tile),
+                    };
+                }
+
+                out.write_tile(row, col, &acc);
+            }
+        }
+
+        out.flush()?;
+        Ok(out)
+    }
+
+    fn offset(&self, row: usize, col: usize) -> usize {
+        if row == 0 || row > self.rows || col == 0 || col > self.cols {
+            panic!(
+                "index [{}, {}] is out of bounds for a {} x {} matrix",
+                row, col, self.rows, self.cols
+            )
+        }
+
+        HEADER_LEN + ((row - 1) * self.cols + col - 1) * T::SIZE
+    }
+
+    fn assert_tile(&self, row: usize, col: usize, rows: usize, cols: usize) {
+        if rows == 0 || cols == 0 {
+            panic!("a tile must have at least one row and column")
+        }
+
+        // both corners have to be inside the matrix
+        self.offset(row, col);
+        self.offset(row + rows - 1, col + cols - 1);
+    }
+
+    fn bytes(&self) -> &[u8] {
+        match &self.map {
+            Map::ReadOnly(map) => map,
+            Map::Writable(map) => map,
+        }
+    }
+
+    fn bytes_mut(&mut self) -> &mut [u8] {
+        self.dirty = true;
+
+        match &mut self.map {
+            Map::ReadOnly(_) => panic!("cannot write to a matrix opened read only"),
+            Map::Writable(map) => map,
+        }
+    }
+}
+
+impl<T> Drop for MappedMatrix<T> {
+    fn drop(&mut self) {
+        // keep the file valid when flush was never called, errors have nowhere to go
+        if let (Map::Writable(map), true) = (&mut self.map, self.dirty) {
+            write_checksum(map);
+            let _ = map.flush();
+        }
+    }
+}
+
+fn write_checksum(map: &mut MmapMut) {
+    let len = map.len() - CHECKSUM_LEN;
+    let checksum = crc32fast::hash(&map[..len]);
+    map[len..].copy_from_slice(&checksum.to_le_bytes());
+}
+
+#[cfg(test)]
+#[path = "./_tests/mapped.rs"]
+mod tests;
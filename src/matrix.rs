@@ -1,15 +1,17 @@
 #![allow(dead_code)]
 
-use std::ops::{Add, AddAssign, Index, Mul, MulAssign};
+use std::ops::{Add, AddAssign, Index, Mul, MulAssign, Sub};
+
+use crate::scalar::Scalar;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub struct Matrix {
-    cells: Vec<Vec<isize>>,
+pub struct Matrix<T = isize> {
+    pub(crate) cells: Vec<Vec<T>>,
 }
 
-impl Matrix {
+impl<T> Matrix<T> {
     //create
-    pub fn new(cells: Vec<Vec<isize>>) -> Self {
+    pub fn new(cells: Vec<Vec<T>>) -> Self {
         let no_of_cols = cells[0].len();
 
         if !cells.iter().all(|col| col.len() == no_of_cols) {
@@ -22,13 +24,60 @@ impl Matrix {
         Matrix { cells }
     }
 
+    pub fn map<U, F: Fn(T) -> U>(&self, f: F) -> Matrix<U>
+    where
+        T: Copy,
+    {
+        let cells = self
+            .cells
+            .iter()
+            .map(|row| row.iter().map(|&cell| f(cell)).collect())
+            .collect();
+
+        Matrix { cells }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cells[0].len()
+    }
+
+    pub(crate) fn assert_square(&self, action: &str) -> usize {
+        // the order of a square matrix, panics naming the action otherwise
+        if self.rows() != self.cols() {
+            panic!(
+                "{} only implemented for square matrices. Found {} x {}",
+                action,
+                self.rows(),
+                self.cols()
+            )
+        }
+
+        self.rows()
+    }
+}
+
+impl<T: Scalar> Matrix<T> {
     pub fn zeroes(rows: usize, cols: usize) -> Self {
         Matrix {
-            cells: vec![vec![0; cols]; rows],
+            cells: vec![vec![T::zero(); cols]; rows],
+        }
+    }
+
+    pub fn identity(size: usize) -> Self {
+        let mut out = Self::zeroes(size, size);
+
+        for (idx, row) in out.cells.iter_mut().enumerate() {
+            row[idx] = T::one();
         }
+
+        out
     }
 
-    pub fn from_list(rows: usize, cols: usize, list: Vec<isize>) -> Self {
+    pub fn from_list(rows: usize, cols: usize, list: Vec<T>) -> Self {
         let size = rows * cols;
 
         if list.len() != size {
@@ -39,8 +88,7 @@ impl Matrix {
         let chunks = list.chunks(cols);
 
         for (row, chunk) in chunks.enumerate() {
-            // since the size of the vector is checked in the step above there's no reason unwrap shouldn't work
-            mat.cells[row] = <&[isize] as TryInto<Vec<isize>>>::try_into(chunk).unwrap();
+            mat.cells[row] = chunk.to_vec();
         }
 
         mat
@@ -73,10 +121,26 @@ impl Matrix {
     }
 }
 
-impl Matrix {
+impl<T: Scalar> Matrix<T> {
+    // transforms
+
+    pub fn transpose(&self) -> Self {
+        let mut out = Matrix::zeroes(self.cols(), self.rows());
+
+        for (r, row) in self.cells.iter().enumerate() {
+            for (c, &cell) in row.iter().enumerate() {
+                out.cells[c][r] = cell;
+            }
+        }
+
+        out
+    }
+}
+
+impl<T: Scalar> Matrix<T> {
     //internal methods
 
-    fn upsize(&mut self, new_rows: usize, new_cols: usize) -> () {
+    fn upsize(&mut self, new_rows: usize, new_cols: usize) {
         let row_diff = new_rows as isize - self.cells.len() as isize;
 
         if row_diff < 0 {
@@ -88,8 +152,8 @@ impl Matrix {
             panic!("Number of columns must be bigger to upsize, current matrix is {} trying to size to {}", self.cells[0].len(), new_cols)
         }
 
-        let col_extension = vec![0; col_diff as usize];
-        let row_extension = vec![vec![0isize; new_cols]; row_diff as usize];
+        let col_extension = vec![T::zero(); col_diff as usize];
+        let row_extension = vec![vec![T::zero(); new_cols]; row_diff as usize];
 
         for row in self.cells.iter_mut() {
             row.extend(col_extension.clone());
@@ -98,7 +162,7 @@ impl Matrix {
         self.cells.extend(row_extension);
     }
 
-    fn downsize(&mut self, new_rows: usize, new_cols: usize) -> () {
+    fn downsize(&mut self, new_rows: usize, new_cols: usize) {
         let row_diff = self.cells.len() as isize - new_rows as isize;
 
         if row_diff < 0 {
@@ -115,17 +179,9 @@ impl Matrix {
             row.truncate(new_cols)
         }
     }
-
-    fn rows(&self) -> usize {
-        self.cells.len()
-    }
-
-    fn cols(&self) -> usize {
-        self.cells[0].len()
-    }
 }
 
-impl Matrix {
+impl<T: Scalar> Matrix<T> {
     // strass algo
 
     pub fn strass(&self, b: &Self) -> Self {
@@ -134,14 +190,18 @@ impl Matrix {
             && self.cols() == b.cols()
             && self.cols() == 2
         {
-            return self.strass_inner(&b);
+            return self.strass_inner(b);
         }
 
         let (out_dim_rows, out_dim_cols) = (self.rows(), b.cols());
 
         // find the closest multiple of two
         let size = self.rows().max(self.cols()).max(b.rows()).max(b.cols());
-        let size = if size % 2 == 0 { size } else { size + 1 };
+        let size = if size.is_multiple_of(2) {
+            size
+        } else {
+            size + 1
+        };
 
         let a = Matrix::from_matrix(size, size, self);
         let b = Matrix::from_matrix(size, size, b);
@@ -157,23 +217,69 @@ impl Matrix {
 
     pub fn strass_inner(&self, b: &Self) -> Self {
         // perform strass_inner algorithm on 2x2 matrix
-        let m1 = (self[[1, 1]] + self[[2, 2]]) * (b[[1, 1]] + b[[2, 2]]);
-        let m2 = (self[[2, 1]] + self[[2, 2]]) * b[[1, 1]];
-        let m3 = self[[1, 1]] * (b[[1, 2]] - b[[2, 2]]);
-        let m4 = self[[2, 2]] * (b[[2, 1]] - b[[1, 1]]);
-        let m5 = (self[[1, 1]] + self[[1, 2]]) * b[[2, 2]];
-        let m6 = (self[[2, 1]] - self[[1, 1]]) * (b[[1, 1]] + b[[1, 2]]);
-        let m7 = (self[[1, 2]] - self[[2, 2]]) * (b[[2, 1]] + b[[2, 2]]);
-
-        Matrix::new(vec![
-            vec![(m1 + m4 - m5 + m7), (m3 + m5)],
-            vec![(m2 + m4), (m1 - m2 + m3 + m6)],
-        ])
+        let [c11, c12, c21, c22] = strass_2x2(
+            [self[[1, 1]], self[[1, 2]], self[[2, 1]], self[[2, 2]]],
+            [b[[1, 1]], b[[1, 2]], b[[2, 1]], b[[2, 2]]],
+        );
+
+        Matrix::new(vec![vec![c11, c12], vec![c21, c22]])
+    }
+}
+
+pub(crate) fn strass_2x2<T>(a: [T; 4], b: [T; 4]) -> [T; 4]
+where
+    T: Clone + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    // the seven strassen products of a 2x2 split given in row major order,
+    // shared by anything that can be split into quadrants
+    let [a11, a12, a21, a22] = a;
+    let [b11, b12, b21, b22] = b;
+
+    let m1 = (a11.clone() + a22.clone()) * (b11.clone() + b22.clone());
+    let m2 = (a21.clone() + a22.clone()) * b11.clone();
+    let m3 = a11.clone() * (b12.clone() - b22.clone());
+    let m4 = a22.clone() * (b21.clone() - b11.clone());
+    let m5 = (a11.clone() + a12.clone()) * b22.clone();
+    let m6 = (a21 - a11) * (b11 + b12);
+    let m7 = (a12 - a22) * (b21 + b22);
+
+    [
+        m1.clone() + m4.clone() - m5.clone() + m7,
+        m3.clone() + m5,
+        m2.clone() + m4,
+        m1 - m2 + m3 + m6,
+    ]
+}
+
+impl<T: Scalar> Matrix<T> {
+    // powers
+
+    pub fn pow(&self, exp: u32) -> Self {
+        // square and multiply with the naive Mul
+        let n = self.assert_square("Matrix powers");
+
+        let mut base = self.clone();
+        let mut out = Matrix::identity(n);
+        let mut exp = exp;
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                out *= base.clone();
+            }
+
+            exp >>= 1;
+
+            if exp > 0 {
+                base *= base.clone();
+            }
+        }
+
+        out
     }
 }
 
-impl Index<[usize; 2]> for Matrix {
-    type Output = isize;
+impl<T> Index<[usize; 2]> for Matrix<T> {
+    type Output = T;
 
     fn index(&self, index: [usize; 2]) -> &Self::Output {
         let (row, col) = (index[0], index[1]);
@@ -182,7 +288,7 @@ impl Index<[usize; 2]> for Matrix {
     }
 }
 
-impl Mul for Matrix {
+impl<T: Scalar> Mul for Matrix<T> {
     type Output = Self;
 
     fn mul(self, b: Self) -> Self::Output {
@@ -195,7 +301,7 @@ impl Mul for Matrix {
         for (r, row) in out.cells.iter_mut().enumerate() {
             for (c, cell) in row.iter_mut().enumerate() {
                 for idx in 1..=self.cols() {
-                    *cell += self[[r + 1, idx]] * b[[idx, c + 1]];
+                    *cell = *cell + self[[r + 1, idx]] * b[[idx, c + 1]];
                 }
             }
         }
@@ -204,18 +310,18 @@ impl Mul for Matrix {
     }
 }
 
-impl MulAssign for Matrix {
+impl<T: Scalar> MulAssign for Matrix<T> {
     fn mul_assign(&mut self, b: Self) {
         let a = self.clone();
         *self = a * b;
     }
 }
 
-impl Add for Matrix {
+impl<T: Scalar> Add for Matrix<T> {
     type Output = Self;
 
     fn add(self, b: Self) -> Self::Output {
-        if self.rows() != b.cols() || self.cols() != b.cols() {
+        if self.rows() != b.rows() || self.cols() != b.cols() {
             panic!(
                 "Matrices must be of the smae size to add. Found {} x {} + {} x {}",
                 self.rows(),
@@ -237,7 +343,7 @@ impl Add for Matrix {
     }
 }
 
-impl AddAssign for Matrix {
+impl<T: Scalar> AddAssign for Matrix<T> {
     fn add_assign(&mut self, b: Self) {
         let a = self.clone();
         *self = a + b;
@@ -245,12 +351,12 @@ impl AddAssign for Matrix {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub struct Blocks {
+pub struct Blocks<T = isize> {
     // struct to handle Matrix Block Multiplication
-    mats: Vec<Vec<Matrix>>,
+    mats: Vec<Vec<Matrix<T>>>,
 }
 
-impl Blocks {
+impl<T: Scalar> Blocks<T> {
     fn zeroes(rows: usize, cols: usize) -> Self {
         Blocks {
             mats: vec![vec![Matrix::zeroes(2, 2); cols]; rows],
@@ -265,7 +371,7 @@ impl Blocks {
         self.mats[0].len()
     }
 
-    pub fn from_matrix(mat: Matrix) -> Self {
+    pub fn from_matrix(mat: Matrix<T>) -> Self {
         //this assumes the matrix is of dimensions 2n x 2m
 
         let mut out = Blocks::zeroes(mat.rows() / 2, mat.cols() / 2);
@@ -293,8 +399,8 @@ impl Blocks {
     }
 }
 
-impl Blocks {
-    pub fn to_matrix(self) -> Matrix {
+impl<T: Scalar> Blocks<T> {
+    pub fn to_matrix(self) -> Matrix<T> {
         let (out_rows, out_cols) = (self.rows() * 2, self.cols() * 2);
 
         let mut out = Matrix::zeroes(out_rows, out_cols);
@@ -325,8 +431,8 @@ impl Blocks {
     }
 }
 
-impl Index<[usize; 2]> for Blocks {
-    type Output = Matrix;
+impl<T> Index<[usize; 2]> for Blocks<T> {
+    type Output = Matrix<T>;
 
     fn index(&self, index: [usize; 2]) -> &Self::Output {
         let (row, col) = (index[0], index[1]);
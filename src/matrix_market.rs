@@ -0,0 +1,524 @@
+use std::fmt::Display;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::str::FromStr;
+
+use crate::error::ReadError;
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+use crate::sparse::CsrMatrix;
+
+// cells from_matrix_market allocates at most, 2 GiB of f64
+pub const DEFAULT_DENSE_LIMIT: usize = 1 << 28;
+
+pub trait MarketElement: Scalar + FromStr + Display {
+    // the field written to the header, integer files also load into the float types
+    const FIELD: &'static str;
+}
+
+macro_rules! impl_market_element {
+    ($field:literal: $($t:ty),*) => {
+        $(
+            impl MarketElement for $t {
+                const FIELD: &'static str = $field;
+            }
+        )*
+    };
+}
+
+impl_market_element!("integer": i8, i16, i32, i64, i128, isize);
+impl_market_element!("real": f32, f64);
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MarketFormat {
+    // one `row col value` line per non zero entry
+    Coordinate,
+    // every entry in column major order
+    Array,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum MarketField {
+    Integer,
+    Real,
+    Pattern,
+}
+
+impl<T: MarketElement> Matrix<T> {
+    // matrix market
+
+    pub fn from_matrix_market<R: Read>(reader: R) -> Result<Self, ReadError> {
+        // symmetric files only list the lower triangle, the rest is mirrored. pattern
+        // files load their entries as one. always dense, so files of more than
+        // DEFAULT_DENSE_LIMIT cells are refused, CsrMatrix::from_matrix_market reads
+        // large sparse files
+        Matrix::from_matrix_market_with_limit(reader, DEFAULT_DENSE_LIMIT)
+    }
+
+    pub fn from_matrix_market_with_limit<R: Read>(
+        reader: R,
+        max_cells: usize,
+    ) -> Result<Self, ReadError> {
+        // the size line is checked against max_cells before anything is allocated, and
+        // an allocation the system cannot satisfy is an error rather than an abort
+        let mut market = MarketReader::new(reader)?;
+        let (rows, cols) = (market.rows, market.cols);
+
+        let fits = rows
+            .checked_mul(cols)
+            .is_some_and(|cells| cells <= max_cells);
+        if !fits {
+            return Err(ReadError::parse(
+                market.size_line,
+                1,
+                format!(
+                    "a {} x {} matrix is over the limit of {} cells to hold densely",
+                    rows, cols, max_cells
+                ),
+            ));
+        }
+
+        let mut cells: Vec<Vec<T>> = Vec::new();
+        let out_of_memory = || {
+            ReadError::parse(
+                market.size_line,
+                1,
+                format!("not enough memory to hold a {} x {} matrix", rows, cols),
+            )
+        };
+        cells.try_reserve_exact(rows).map_err(|_| out_of_memory())?;
+        for _ in 0..rows {
+            let mut row = Vec::new();
+            row.try_reserve_exact(cols).map_err(|_| out_of_memory())?;
+            row.resize(cols, T::zero());
+            cells.push(row);
+        }
+
+        while let Some((r, c, value)) = market.next_entry::<T>()? {
+            cells[r][c] = value;
+            if market.symmetric {
+                cells[c][r] = value;
+            }
+        }
+
+        Ok(Matrix::new(cells))
+    }
+
+    pub fn to_matrix_market<W: Write>(
+        &self,
+        mut writer: W,
+        format: MarketFormat,
+    ) -> std::io::Result<()> {
+        // square matrices equal to their transpose are written as symmetric, keeping
+        // only the lower triangle
+        let symmetric = self.rows() == self.cols() && *self == self.transpose();
+        let (format_name, symmetry) = (
+            match format {
+                MarketFormat::Coordinate => "coordinate",
+                MarketFormat::Array => "array",
+            },
+            if symmetric { "symmetric" } else { "general" },
+        );
+
+        writeln!(
+            writer,
+            "%%MatrixMarket matrix {} {} {}",
+            format_name,
+            T::FIELD,
+            symmetry
+        )?;
+
+        let stored = |r: usize, c: usize| !symmetric || r >= c;
+
+        match format {
+            MarketFormat::Coordinate => {
+                let entries: Vec<(usize, usize, T)> = (0..self.cols())
+                    .flat_map(|c| (0..self.rows()).map(move |r| (r, c)))
+                    .filter(|&(r, c)| stored(r, c) && self.cells[r][c] != T::zero())
+                    .map(|(r, c)| (r, c, self.cells[r][c]))
+                    .collect();
+
+                writeln!(writer, "{} {} {}", self.rows(), self.cols(), entries.len())?;
+                for (r, c, value) in entries {
+                    writeln!(writer, "{} {} {}", r + 1, c + 1, value)?;
+                }
+            }
+            MarketFormat::Array => {
+                writeln!(writer, "{} {}", self.rows(), self.cols())?;
+                for c in 0..self.cols() {
+                    for r in 0..self.rows() {
+                        if stored(r, c) {
+                            writeln!(writer, "{}", self.cells[r][c])?;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: MarketElement> CsrMatrix<T> {
+    pub fn from_matrix_market<R: Read>(reader: R) -> Result<Self, ReadError> {
+        // memory grows with the entries actually listed, not with rows x cols, so
+        // large sparse files load. symmetric files store both triangles
+        let mut market = MarketReader::new(reader)?;
+        let mut triplets = Vec::new();
+
+        while let Some((r, c, value)) = market.next_entry::<T>()? {
+            triplets.push(([r + 1, c + 1], value));
+            if market.symmetric && r != c {
+                triplets.push(([c + 1, r + 1], value));
+            }
+        }
+
+        Ok(CsrMatrix::from_triplets(market.rows, market.cols, triplets))
+    }
+}
+
+struct MarketReader<R: Read> {
+    // the header and size line of a file, then its entries one at a time
+    lines: Lines<R>,
+    format: MarketFormat,
+    field: MarketField,
+    symmetric: bool,
+    rows: usize,
+    cols: usize,
+    size_line: usize,
+    entries: usize,
+    read: usize,
+    // where the next array entry goes, array files list entries in column major order
+    next: (usize, usize),
+}
+
+impl<R: Read> MarketReader<R> {
+    fn new(reader: R) -> Result<Self, ReadError> {
+        let mut lines = Lines::new(reader);
+
+        let (line_no, header) = lines
+            .next_line()?
+            .ok_or_else(|| ReadError::parse(1, 1, "missing %%MatrixMarket header"))?;
+        let (format, field, symmetric) = parse_header(line_no, &header)?;
+
+        let (line_no, size) = lines
+            .next_data()?
+            .ok_or_else(|| ReadError::parse(line_no + 1, 1, "missing size line"))?;
+        let size_tokens = tokens(&size);
+        let expected = if format == MarketFormat::Coordinate {
+            3
+        } else {
+            2
+        };
+        if size_tokens.len() != expected {
+            return Err(ReadError::parse(
+                line_no,
+                1,
+                format!(
+                    "size line needs {} values, found {}",
+                    expected,
+                    size_tokens.len()
+                ),
+            ));
+        }
+
+        let dims: Vec<usize> = size_tokens
+            .iter()
+            .map(|&(column, token)| {
+                token.parse().map_err(|_| {
+                    ReadError::parse(line_no, column, format!("invalid size `{}`", token))
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        let (rows, cols) = (dims[0], dims[1]);
+
+        if rows == 0 || cols == 0 {
+            return Err(ReadError::parse(
+                line_no,
+                1,
+                "matrix must have at least one row and column",
+            ));
+        }
+        if symmetric && rows != cols {
+            return Err(ReadError::parse(
+                line_no,
+                1,
+                "symmetric matrix must be square",
+            ));
+        }
+
+        let entries = match format {
+            MarketFormat::Coordinate => Some(dims[2]),
+            MarketFormat::Array if symmetric => rows
+                .checked_add(1)
+                .and_then(|n| n.checked_mul(rows))
+                .map(|n| n / 2),
+            MarketFormat::Array => rows.checked_mul(cols),
+        }
+        .ok_or_else(|| {
+            ReadError::parse(
+                line_no,
+                1,
+                format!("a {} x {} array has too many entries", rows, cols),
+            )
+        })?;
+
+        Ok(MarketReader {
+            lines,
+            format,
+            field,
+            symmetric,
+            rows,
+            cols,
+            size_line: line_no,
+            entries,
+            read: 0,
+            next: (0, 0),
+        })
+    }
+
+    fn next_entry<T: MarketElement>(&mut self) -> Result<Option<(usize, usize, T)>, ReadError> {
+        // the next (row, col, value) numbered from 0, None once every declared entry
+        // is read and nothing follows them
+        if self.read == self.entries {
+            if let Some((line_no, _)) = self.lines.next_data()? {
+                return Err(ReadError::parse(
+                    line_no,
+                    1,
+                    format!("more than the {} declared entries", self.entries),
+                ));
+            }
+
+            return Ok(None);
+        }
+
+        let (line_no, line) = self.lines.next_data()?.ok_or_else(|| {
+            ReadError::parse(
+                self.lines.line_no + 1,
+                1,
+                format!("expected {} entries, found {}", self.entries, self.read),
+            )
+        })?;
+        let fields = tokens(&line);
+
+        let (r, c, value_token) = if self.format == MarketFormat::Coordinate {
+            let needed = if self.field == MarketField::Pattern {
+                2
+            } else {
+                3
+            };
+            if fields.len() != needed {
+                return Err(ReadError::parse(
+                    line_no,
+                    1,
+                    format!("entry needs {} values, found {}", needed, fields.len()),
+                ));
+            }
+
+            let r = parse_index(line_no, fields[0], self.rows)?;
+            let c = parse_index(line_no, fields[1], self.cols)?;
+            if self.symmetric && c > r {
+                return Err(ReadError::parse(
+                    line_no,
+                    fields[0].0,
+                    "symmetric entries must be in the lower triangle",
+                ));
+            }
+
+            (r, c, fields.get(2).copied())
+        } else {
+            if fields.len() != 1 {
+                return Err(ReadError::parse(
+                    line_no,
+                    1,
+                    format!("entry needs 1 value, found {}", fields.len()),
+                ));
+            }
+
+            let (r, c) = self.next;
+            self.next = if r + 1 < self.rows {
+                (r + 1, c)
+            } else if self.symmetric {
+                (c + 1, c + 1)
+            } else {
+                (0, c + 1)
+            };
+
+            (r, c, Some(fields[0]))
+        };
+
+        let value = match value_token {
+            None => T::one(),
+            Some((column, token)) => parse_value::<T>(line_no, column, token, self.field)?,
+        };
+
+        self.read += 1;
+        Ok(Some((r, c, value)))
+    }
+}
+
+struct Lines<R: Read> {
+    reader: BufReader<R>,
+    line_no: usize,
+}
+
+impl<R: Read> Lines<R> {
+    fn new(reader: R) -> Self {
+        Lines {
+            reader: BufReader::new(reader),
+            line_no: 0,
+        }
+    }
+
+    fn next_line(&mut self) -> Result<Option<(usize, String)>, ReadError> {
+        let mut line = String::new();
+
+        if self.reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+
+        self.line_no += 1;
+        Ok(Some((self.line_no, line)))
+    }
+
+    fn next_data(&mut self) -> Result<Option<(usize, String)>, ReadError> {
+        // skips % comments and blank lines
+        while let Some((line_no, line)) = self.next_line()? {
+            let trimmed = line.trim();
+            if !trimmed.is_empty() && !trimmed.starts_with('%') {
+                return Ok(Some((line_no, line)));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+fn tokens(line: &str) -> Vec<(usize, &str)> {
+    // whitespace separated tokens with their 1 based column
+    let mut out = Vec::new();
+    let mut start = None;
+
+    for (idx, ch) in line
+        .char_indices()
+        .chain(std::iter::once((line.len(), ' ')))
+    {
+        match (start, ch.is_whitespace()) {
+            (None, false) => start = Some(idx),
+            (Some(s), true) => {
+                out.push((line[..s].chars().count() + 1, &line[s..idx]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    out
+}
+
+fn parse_header(
+    line_no: usize,
+    line: &str,
+) -> Result<(MarketFormat, MarketField, bool), ReadError> {
+    let fields = tokens(line);
+
+    if fields.len() != 5
+        || fields[0].1 != "%%MatrixMarket"
+        || !fields[1].1.eq_ignore_ascii_case("matrix")
+    {
+        return Err(ReadError::parse(
+            line_no,
+            1,
+            "expected `%%MatrixMarket matrix <format> <field> <symmetry>`",
+        ));
+    }
+
+    let (column, format) = fields[2];
+    let format = match format.to_ascii_lowercase().as_str() {
+        "coordinate" => MarketFormat::Coordinate,
+        "array" => MarketFormat::Array,
+        _ => {
+            return Err(ReadError::parse(
+                line_no,
+                column,
+                format!("unsupported format `{}`", format),
+            ))
+        }
+    };
+
+    let (column, field) = fields[3];
+    let field = match field.to_ascii_lowercase().as_str() {
+        "integer" => MarketField::Integer,
+        "real" => MarketField::Real,
+        "pattern" if format == MarketFormat::Coordinate => MarketField::Pattern,
+        _ => {
+            return Err(ReadError::parse(
+                line_no,
+                column,
+                format!("unsupported field `{}`", field),
+            ))
+        }
+    };
+
+    let (column, symmetry) = fields[4];
+    let symmetric = match symmetry.to_ascii_lowercase().as_str() {
+        "general" => false,
+        "symmetric" => true,
+        _ => {
+            return Err(ReadError::parse(
+                line_no,
+                column,
+                format!("unsupported symmetry `{}`", symmetry),
+            ))
+        }
+    };
+
+    Ok((format, field, symmetric))
+}
+
+fn parse_index(
+    line_no: usize,
+    (column, token): (usize, &str),
+    bound: usize,
+) -> Result<usize, ReadError> {
+    match token.parse::<usize>() {
+        Ok(idx) if (1..=bound).contains(&idx) => Ok(idx - 1),
+        Ok(_) => Err(ReadError::parse(
+            line_no,
+            column,
+            format!("index {} outside 1..={}", token, bound),
+        )),
+        Err(_) => Err(ReadError::parse(
+            line_no,
+            column,
+            format!("invalid index `{}`", token),
+        )),
+    }
+}
+
+fn parse_value<T: MarketElement>(
+    line_no: usize,
+    column: usize,
+    token: &str,
+    field: MarketField,
+) -> Result<T, ReadError> {
+    // integer fields are checked to really be integers, the element type decides the rest
+    if field == MarketField::Integer && token.parse::<i128>().is_err() {
+        return Err(ReadError::parse(
+            line_no,
+            column,
+            format!("invalid integer `{}`", token),
+        ));
+    }
+
+    token.parse().map_err(|_| {
+        ReadError::parse(
+            line_no,
+            column,
+            format!("`{}` is not a valid {} value", token, T::FIELD),
+        )
+    })
+}
+
+#[cfg(test)]
+#[path = "./_tests/matrix_market.rs"]
+mod tests;
@@ -0,0 +1,210 @@
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+
+type Cells = Vec<Vec<isize>>;
+
+impl Matrix {
+    // integer normal forms
+
+    pub fn hermite_normal_form(&self) -> Result<(Matrix, Matrix), MatrixError> {
+        // row style hermite normal form, returns (H, U) with U * A = H and U unimodular
+        let (rows, cols) = (self.rows(), self.cols());
+
+        let mut h = self.cells.clone();
+        let mut u = Matrix::identity(rows).cells;
+
+        let mut pivot = 0;
+
+        for col in 0..cols {
+            if pivot == rows {
+                break;
+            }
+
+            // euclid down the column until only the pivot row is non zero
+            loop {
+                let smallest = (pivot..rows)
+                    .filter(|&r| h[r][col] != 0)
+                    .min_by_key(|&r| h[r][col].unsigned_abs());
+
+                let smallest = match smallest {
+                    Some(r) => r,
+                    None => break,
+                };
+
+                h.swap(pivot, smallest);
+                u.swap(pivot, smallest);
+
+                let mut cleared = true;
+
+                for r in (pivot + 1)..rows {
+                    let q = checked_div(h[r][col], h[pivot][col])?;
+
+                    if q != 0 {
+                        row_sub(&mut h, r, pivot, q)?;
+                        row_sub(&mut u, r, pivot, q)?;
+                    }
+
+                    if h[r][col] != 0 {
+                        cleared = false;
+                    }
+                }
+
+                if cleared {
+                    break;
+                }
+            }
+
+            if h[pivot][col] == 0 {
+                continue;
+            }
+
+            if h[pivot][col] < 0 {
+                row_negate(&mut h, pivot)?;
+                row_negate(&mut u, pivot)?;
+            }
+
+            // reduce the entries above the pivot into 0..pivot
+            for r in 0..pivot {
+                let q = h[r][col]
+                    .checked_div_euclid(h[pivot][col])
+                    .ok_or(MatrixError::Overflow)?;
+
+                if q != 0 {
+                    row_sub(&mut h, r, pivot, q)?;
+                    row_sub(&mut u, r, pivot, q)?;
+                }
+            }
+
+            pivot += 1;
+        }
+
+        Ok((Matrix::new(h), Matrix::new(u)))
+    }
+
+    pub fn smith_normal_form(&self) -> Result<(Matrix, Matrix, Matrix), MatrixError> {
+        // returns (S, U, V) with U * A * V = S, S diagonal with each entry dividing the next
+        let (rows, cols) = (self.rows(), self.cols());
+
+        let mut s = self.cells.clone();
+        let mut u = Matrix::identity(rows).cells;
+        let mut v = Matrix::identity(cols).cells;
+
+        for t in 0..rows.min(cols) {
+            loop {
+                let smallest = (t..rows)
+                    .flat_map(|r| (t..cols).map(move |c| (r, c)))
+                    .filter(|&(r, c)| s[r][c] != 0)
+                    .min_by_key(|&(r, c)| s[r][c].unsigned_abs());
+
+                let (r, c) = match smallest {
+                    Some(pos) => pos,
+                    // the rest of the matrix is zero so we are done
+                    None => return Ok((Matrix::new(s), Matrix::new(u), Matrix::new(v))),
+                };
+
+                s.swap(t, r);
+                u.swap(t, r);
+                col_swap(&mut s, t, c);
+                col_swap(&mut v, t, c);
+
+                let mut cleared = true;
+
+                for r in (t + 1)..rows {
+                    let q = checked_div(s[r][t], s[t][t])?;
+
+                    if q != 0 {
+                        row_sub(&mut s, r, t, q)?;
+                        row_sub(&mut u, r, t, q)?;
+                    }
+
+                    if s[r][t] != 0 {
+                        cleared = false;
+                    }
+                }
+
+                for c in (t + 1)..cols {
+                    let q = checked_div(s[t][c], s[t][t])?;
+
+                    if q != 0 {
+                        col_sub(&mut s, c, t, q)?;
+                        col_sub(&mut v, c, t, q)?;
+                    }
+
+                    if s[t][c] != 0 {
+                        cleared = false;
+                    }
+                }
+
+                if !cleared {
+                    continue;
+                }
+
+                // the pivot has to divide everything left, otherwise fold the offending row in
+                let offending = ((t + 1)..rows).find(|&r| {
+                    ((t + 1)..cols).any(|c| s[r][c].checked_rem(s[t][t]).unwrap_or(0) != 0)
+                });
+
+                match offending {
+                    Some(r) => {
+                        row_sub(&mut s, t, r, -1)?;
+                        row_sub(&mut u, t, r, -1)?;
+                    }
+                    None => break,
+                }
+            }
+
+            if s[t][t] < 0 {
+                row_negate(&mut s, t)?;
+                row_negate(&mut u, t)?;
+            }
+        }
+
+        Ok((Matrix::new(s), Matrix::new(u), Matrix::new(v)))
+    }
+}
+
+fn checked_div(a: isize, b: isize) -> Result<isize, MatrixError> {
+    a.checked_div(b).ok_or(MatrixError::Overflow)
+}
+
+fn row_sub(cells: &mut Cells, target: usize, source: usize, q: isize) -> Result<(), MatrixError> {
+    // row[target] -= q * row[source]
+    for c in 0..cells[target].len() {
+        cells[target][c] = q
+            .checked_mul(cells[source][c])
+            .and_then(|x| cells[target][c].checked_sub(x))
+            .ok_or(MatrixError::Overflow)?;
+    }
+
+    Ok(())
+}
+
+fn col_sub(cells: &mut Cells, target: usize, source: usize, q: isize) -> Result<(), MatrixError> {
+    // col[target] -= q * col[source]
+    for row in cells.iter_mut() {
+        row[target] = q
+            .checked_mul(row[source])
+            .and_then(|x| row[target].checked_sub(x))
+            .ok_or(MatrixError::Overflow)?;
+    }
+
+    Ok(())
+}
+
+fn row_negate(cells: &mut Cells, row: usize) -> Result<(), MatrixError> {
+    for cell in cells[row].iter_mut() {
+        *cell = cell.checked_neg().ok_or(MatrixError::Overflow)?;
+    }
+
+    Ok(())
+}
+
+fn col_swap(cells: &mut Cells, a: usize, b: usize) {
+    for row in cells.iter_mut() {
+        row.swap(a, b);
+    }
+}
+
+#[cfg(test)]
+#[path = "./_tests/normal_form.rs"]
+mod tests;
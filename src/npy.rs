@@ -0,0 +1,372 @@
+use std::collections::BTreeMap;
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use crate::error::ReadError;
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+
+const NPY_MAGIC: &[u8] = b"\x93NUMPY";
+
+pub trait NpyElement: Scalar {
+    // the little endian dtype written by write_npy
+    const DESCR: &'static str;
+
+    // integer dtypes convert into any type that holds the value, float dtypes only into floats
+    fn from_integer(value: i64) -> Option<Self>;
+    fn from_float(value: f64) -> Option<Self>;
+    fn write_le(self, out: &mut Vec<u8>);
+}
+
+macro_rules! impl_npy_int {
+    ($($t:ty => $descr:literal),*) => {
+        $(
+            impl NpyElement for $t {
+                const DESCR: &'static str = $descr;
+
+                fn from_integer(value: i64) -> Option<Self> {
+                    value.try_into().ok()
+                }
+
+                fn from_float(_: f64) -> Option<Self> {
+                    None
+                }
+
+                fn write_le(self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_npy_int!(i32 => "<i4", i64 => "<i8", isize => "<i8");
+
+macro_rules! impl_npy_float {
+    ($($t:ty => $descr:literal),*) => {
+        $(
+            impl NpyElement for $t {
+                const DESCR: &'static str = $descr;
+
+                fn from_integer(value: i64) -> Option<Self> {
+                    Some(value as $t)
+                }
+
+                fn from_float(value: f64) -> Option<Self> {
+                    Some(value as $t)
+                }
+
+                fn write_le(self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_npy_float!(f32 => "<f4", f64 => "<f8");
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Dtype {
+    Int32,
+    Int64,
+    Float32,
+    Float64,
+}
+
+impl Dtype {
+    fn size(self) -> usize {
+        match self {
+            Dtype::Int32 | Dtype::Float32 => 4,
+            Dtype::Int64 | Dtype::Float64 => 8,
+        }
+    }
+}
+
+impl<T: NpyElement> Matrix<T> {
+    // numpy
+
+    pub fn read_npy<R: Read>(mut reader: R) -> Result<Self, ReadError> {
+        // 2-D int32, int64, float32 and float64 arrays of either byte order, in C or
+        // fortran order
+        let mut magic = [0u8; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic[..6] != NPY_MAGIC {
+            return Err(format_error("missing NUMPY magic string"));
+        }
+
+        let header_len = match magic[6] {
+            1 => {
+                let mut len = [0u8; 2];
+                reader.read_exact(&mut len)?;
+                u16::from_le_bytes(len) as usize
+            }
+            2 | 3 => {
+                let mut len = [0u8; 4];
+                reader.read_exact(&mut len)?;
+                u32::from_le_bytes(len) as usize
+            }
+            version => return Err(format_error(format!("unsupported npy version {}", version))),
+        };
+
+        // buffers grow with the data actually read, so a corrupt length or shape ends in
+        // an error rather than a huge allocation
+        let header = read_up_to(&mut reader, header_len)?;
+        let header =
+            String::from_utf8(header).map_err(|_| format_error("npy header is not utf-8"))?;
+        let (dtype, big_endian, fortran_order, rows, cols) = parse_header(&header)?;
+
+        let len = rows
+            .checked_mul(cols)
+            .and_then(|cells| cells.checked_mul(dtype.size()))
+            .ok_or_else(|| format_error(format!("npy shape ({}, {}) is too large", rows, cols)))?;
+        let data = read_up_to(&mut reader, len)?;
+
+        let mut out = Matrix::zeroes(rows, cols);
+        for (idx, bytes) in data.chunks_exact(dtype.size()).enumerate() {
+            let (r, c) = if fortran_order {
+                (idx % rows, idx / rows)
+            } else {
+                (idx / cols, idx % cols)
+            };
+
+            out.cells[r][c] = decode::<T>(dtype, big_endian, bytes)?;
+        }
+
+        Ok(out)
+    }
+
+    pub fn write_npy<W: Write>(&self, mut writer: W) -> std::io::Result<()> {
+        // version 1.0, little endian and C order
+        let mut header = format!(
+            "{{'descr': '{}', 'fortran_order': False, 'shape': ({}, {}), }}",
+            T::DESCR,
+            self.rows(),
+            self.cols()
+        );
+
+        // the data has to start on a 64 byte boundary, the header ends in a newline
+        let unpadded = NPY_MAGIC.len() + 2 + 2 + header.len() + 1;
+        header.push_str(&" ".repeat((64 - unpadded % 64) % 64));
+        header.push('\n');
+
+        writer.write_all(NPY_MAGIC)?;
+        writer.write_all(&[1, 0])?;
+        writer.write_all(&(header.len() as u16).to_le_bytes())?;
+        writer.write_all(header.as_bytes())?;
+
+        let mut data = Vec::with_capacity(self.rows() * self.cols() * 8);
+        for &cell in self.cells.iter().flatten() {
+            cell.write_le(&mut data);
+        }
+
+        writer.write_all(&data)
+    }
+}
+
+pub fn read_npz<T: NpyElement, R: Read + Seek>(
+    mut reader: R,
+) -> Result<BTreeMap<String, Matrix<T>>, ReadError> {
+    // an npz file is a zip archive of npy files, entries may be stored (np.savez) or
+    // deflated (np.savez_compressed). names lose their .npy extension
+    let end = reader.seek(SeekFrom::End(0))?;
+    let tail_len = end.min(22 + u16::MAX as u64);
+    reader.seek(SeekFrom::Start(end - tail_len))?;
+    let mut tail = vec![0u8; tail_len as usize];
+    reader.read_exact(&mut tail)?;
+
+    let eocd = (0..tail.len().saturating_sub(21))
+        .rev()
+        .find(|&idx| le_u32(&tail, idx) == 0x0605_4b50)
+        .ok_or_else(|| format_error("missing zip end of central directory"))?;
+    let entries = le_u16(&tail, eocd + 10) as usize;
+    let directory_len = le_u32(&tail, eocd + 12) as usize;
+    let directory_offset = le_u32(&tail, eocd + 16) as u64;
+
+    reader.seek(SeekFrom::Start(directory_offset))?;
+    let directory = read_up_to(&mut reader, directory_len)?;
+
+    let mut records = Vec::with_capacity(entries);
+    let mut pos = 0;
+    for _ in 0..entries {
+        if pos + 46 > directory.len() || le_u32(&directory, pos) != 0x0201_4b50 {
+            return Err(format_error("corrupt zip central directory"));
+        }
+
+        let method = le_u16(&directory, pos + 10);
+        let compressed = le_u32(&directory, pos + 20) as usize;
+        let uncompressed = le_u32(&directory, pos + 24) as usize;
+        let name_len = le_u16(&directory, pos + 28) as usize;
+        let extra_len = le_u16(&directory, pos + 30) as usize;
+        let comment_len = le_u16(&directory, pos + 32) as usize;
+        let offset = le_u32(&directory, pos + 42) as u64;
+        let name = directory
+            .get(pos + 46..pos + 46 + name_len)
+            .ok_or_else(|| format_error("corrupt zip central directory"))?;
+        let name = String::from_utf8_lossy(name).into_owned();
+
+        records.push((name, method, compressed, uncompressed, offset));
+        pos += 46 + name_len + extra_len + comment_len;
+    }
+
+    let mut out = BTreeMap::new();
+    for (name, method, compressed, uncompressed, offset) in records {
+        // sizes come from the central directory, numpy writes zip64 placeholders locally
+        reader.seek(SeekFrom::Start(offset))?;
+        let mut local = [0u8; 30];
+        reader.read_exact(&mut local)?;
+        if le_u32(&local, 0) != 0x0403_4b50 {
+            return Err(format_error(format!("corrupt zip entry `{}`", name)));
+        }
+        let skip = le_u16(&local, 26) as i64 + le_u16(&local, 28) as i64;
+        reader.seek(SeekFrom::Current(skip))?;
+
+        let data = read_up_to(&mut reader, compressed)?;
+
+        let data = match method {
+            0 => data,
+            // inflating stops at the size the directory gives, so a small member cannot
+            // expand without bound
+            8 => miniz_oxide::inflate::decompress_to_vec_with_limit(&data, uncompressed)
+                .map_err(|_| format_error(format!("corrupt deflate data in `{}`", name)))?,
+            _ => {
+                return Err(format_error(format!(
+                    "unsupported zip compression method {} in `{}`",
+                    method, name
+                )))
+            }
+        };
+
+        if data.len() != uncompressed {
+            return Err(format_error(format!(
+                "`{}` holds {} bytes but the zip directory gives {}",
+                name,
+                data.len(),
+                uncompressed
+            )));
+        }
+
+        let key = name.strip_suffix(".npy").unwrap_or(&name).to_string();
+        out.insert(key, Matrix::read_npy(data.as_slice())?);
+    }
+
+    Ok(out)
+}
+
+fn parse_header(header: &str) -> Result<(Dtype, bool, bool, usize, usize), ReadError> {
+    // the header is a python dict literal such as
+    // {'descr': '<f8', 'fortran_order': False, 'shape': (3, 4), }
+    let descr = header_value(header, "descr")?;
+    let descr = descr
+        .strip_prefix(['\'', '"'])
+        .and_then(|rest| rest.split(['\'', '"']).next())
+        .ok_or_else(|| format_error("npy descr is not a string"))?;
+
+    let mut chars = descr.chars();
+    let (big_endian, kind) = match (chars.next(), chars.as_str()) {
+        (Some('<' | '='), kind) => (false, kind),
+        (Some('>'), kind) => (true, kind),
+        _ => return Err(format_error(format!("unsupported dtype `{}`", descr))),
+    };
+    let dtype = match kind {
+        "i4" => Dtype::Int32,
+        "i8" => Dtype::Int64,
+        "f4" => Dtype::Float32,
+        "f8" => Dtype::Float64,
+        _ => return Err(format_error(format!("unsupported dtype `{}`", descr))),
+    };
+
+    let fortran = header_value(header, "fortran_order")?;
+    let fortran_order = if fortran.starts_with("True") {
+        true
+    } else if fortran.starts_with("False") {
+        false
+    } else {
+        return Err(format_error("npy fortran_order is not a boolean"));
+    };
+
+    let shape = header_value(header, "shape")?;
+    let shape = shape
+        .strip_prefix('(')
+        .and_then(|rest| rest.split(')').next())
+        .ok_or_else(|| format_error("npy shape is not a tuple"))?;
+    let dims: Vec<usize> = shape
+        .split(',')
+        .map(str::trim)
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| {
+            dim.parse()
+                .map_err(|_| format_error(format!("invalid npy shape `({})`", shape)))
+        })
+        .collect::<Result<_, _>>()?;
+
+    match dims[..] {
+        [rows, cols] if rows > 0 && cols > 0 => Ok((dtype, big_endian, fortran_order, rows, cols)),
+        [_, _] => Err(format_error("matrix must have at least one row and column")),
+        _ => Err(format_error(format!(
+            "expected a 2-D array, found shape ({})",
+            shape
+        ))),
+    }
+}
+
+fn header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, ReadError> {
+    // the text following `'key':`, with leading whitespace removed
+    ["'", "\""]
+        .iter()
+        .find_map(|quote| {
+            let pattern = format!("{}{}{}", quote, key, quote);
+            let start = header.find(&pattern)? + pattern.len();
+            let rest = header[start..].trim_start().strip_prefix(':')?;
+            Some(rest.trim_start())
+        })
+        .ok_or_else(|| format_error(format!("npy header has no `{}`", key)))
+}
+
+fn decode<T: NpyElement>(dtype: Dtype, big_endian: bool, bytes: &[u8]) -> Result<T, ReadError> {
+    macro_rules! value {
+        ($t:ty) => {{
+            let bytes = bytes.try_into().unwrap();
+            if big_endian {
+                <$t>::from_be_bytes(bytes)
+            } else {
+                <$t>::from_le_bytes(bytes)
+            }
+        }};
+    }
+
+    let value = match dtype {
+        Dtype::Int32 => T::from_integer(value!(i32) as i64),
+        Dtype::Int64 => T::from_integer(value!(i64)),
+        Dtype::Float32 => T::from_float(value!(f32) as f64),
+        Dtype::Float64 => T::from_float(value!(f64)),
+    };
+
+    value.ok_or_else(|| format_error(format!("{:?} value does not fit the element type", dtype)))
+}
+
+fn read_up_to<R: Read>(reader: &mut R, len: usize) -> Result<Vec<u8>, ReadError> {
+    let mut out = Vec::new();
+    reader.by_ref().take(len as u64).read_to_end(&mut out)?;
+
+    if out.len() < len {
+        return Err(ReadError::Io(std::io::ErrorKind::UnexpectedEof.into()));
+    }
+
+    Ok(out)
+}
+
+fn le_u16(bytes: &[u8], at: usize) -> u16 {
+    u16::from_le_bytes([bytes[at], bytes[at + 1]])
+}
+
+fn le_u32(bytes: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]])
+}
+
+fn format_error(message: impl Into<String>) -> ReadError {
+    ReadError::Format(message.into())
+}
+
+#[cfg(test)]
+#[path = "./_tests/npy.rs"]
+mod tests;
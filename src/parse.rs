@@ -0,0 +1,151 @@
+use std::str::FromStr;
+
+use crate::error::ParseMatrixError;
+use crate::matrix::Matrix;
+
+/// Builds a [`Matrix`] from rows separated by `;`, like the text accepted by `FromStr`.
+///
+/// ```
+/// use matrix_rs::matrix;
+/// use matrix_rs::matrix::Matrix;
+///
+/// let a = matrix![1, 2; 3, 4];
+///
+/// assert_eq!(Matrix::new(vec![vec![1, 2], vec![3, 4]]), a);
+/// ```
+///
+/// Rows of different lengths are rejected at compile time:
+///
+/// ```compile_fail
+/// use matrix_rs::matrix;
+///
+/// let a = matrix![1, 2; 3];
+/// ```
+#[macro_export]
+macro_rules! matrix {
+    ($($($x:expr),+);+ $(;)?) => {{
+        const _: () = {
+            let widths = [$($crate::matrix!(@count $($x),+)),+];
+            let mut idx = 1;
+            while idx < widths.len() {
+                assert!(widths[idx] == widths[0], "matrix! rows must all have the same length");
+                idx += 1;
+            }
+        };
+
+        $crate::matrix::Matrix::new(vec![$(vec![$($x),+]),+])
+    }};
+    (@count $($x:expr),+) => {
+        <[()]>::len(&[$($crate::matrix!(@unit $x)),+])
+    };
+    (@unit $x:expr) => {
+        ()
+    };
+}
+
+impl<T: FromStr> FromStr for Matrix<T> {
+    type Err = ParseMatrixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // rows are separated by `;` or new lines and elements by `,` or spaces, the whole
+        // thing may be wrapped in [ ]. positions count characters from 1
+        let chars: Vec<char> = s.chars().collect();
+
+        let first = chars.iter().position(|c| !c.is_whitespace());
+        let last = chars.iter().rposition(|c| !c.is_whitespace());
+        let (start, end) = match (first, last) {
+            (Some(first), Some(last)) => (first, last + 1),
+            _ => return Err(ParseMatrixError::new(1, "empty matrix")),
+        };
+
+        let (start, end) = match (chars[start], chars[end - 1]) {
+            ('[', ']') if end - start >= 2 => (start + 1, end - 1),
+            ('[', _) => return Err(ParseMatrixError::new(end + 1, "missing closing `]`")),
+            (_, ']') => return Err(ParseMatrixError::new(end, "unexpected `]`")),
+            _ => (start, end),
+        };
+
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        let mut row: Vec<T> = Vec::new();
+        let mut row_start = 0;
+        let mut token_start = None;
+        let mut after_comma = None;
+
+        for idx in start..=end {
+            let ch = chars.get(idx).copied().filter(|_| idx < end);
+
+            let ends_token = !matches!(ch, Some(c) if !c.is_whitespace() && !",;[]".contains(c));
+            if let (true, Some(from)) = (ends_token, token_start) {
+                let token: String = chars[from..idx].iter().collect();
+                let value = token.parse().map_err(|_| {
+                    ParseMatrixError::new(from + 1, format!("invalid element `{}`", token))
+                })?;
+
+                if row.is_empty() {
+                    row_start = from;
+                }
+                row.push(value);
+                token_start = None;
+                after_comma = None;
+            }
+
+            match ch {
+                Some(',') => {
+                    if row.is_empty() || after_comma.is_some() {
+                        return Err(ParseMatrixError::new(
+                            idx + 1,
+                            "expected an element before `,`",
+                        ));
+                    }
+                    after_comma = Some(idx);
+                }
+                Some(c @ ('[' | ']')) => {
+                    return Err(ParseMatrixError::new(
+                        idx + 1,
+                        format!("unexpected `{}`", c),
+                    ));
+                }
+                None | Some(';') | Some('\n') => {
+                    if let Some(comma) = after_comma {
+                        return Err(ParseMatrixError::new(comma + 1, "trailing `,`"));
+                    }
+
+                    // blank rows are skipped, so `[1 2;\n 3 4]` and a trailing `;` are fine
+                    if !row.is_empty() {
+                        if let Some(width) = rows.first().map(Vec::len) {
+                            if row.len() != width {
+                                return Err(ParseMatrixError::new(
+                                    row_start + 1,
+                                    format!(
+                                        "row {} has {} elements, expected {}",
+                                        rows.len() + 1,
+                                        row.len(),
+                                        width
+                                    ),
+                                ));
+                            }
+                        }
+
+                        rows.push(std::mem::take(&mut row));
+                    }
+                }
+                Some(c) if c.is_whitespace() => {}
+                Some(_) => {
+                    if token_start.is_none() {
+                        token_start = Some(idx);
+                    }
+                }
+            }
+        }
+
+        if rows.is_empty() {
+            return Err(ParseMatrixError::new(start + 1, "empty matrix"));
+        }
+
+        Ok(Matrix::new(rows))
+    }
+}
+
+#[cfg(test)]
+#[path = "./_tests/parse.rs"]
+mod tests;
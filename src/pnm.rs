@@ -0,0 +1,303 @@
+use std::io::{self, Read, Write};
+
+use crate::error::ReadError;
+use crate::matrix::Matrix;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PnmEncoding {
+    // P2 and P3, whitespace separated decimal samples
+    Ascii,
+    // P5 and P6, one byte per sample, or two big endian bytes when maxval is over 255
+    Binary,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PnmOptions {
+    pub encoding: PnmEncoding,
+    // the largest sample value, between 1 and 65535
+    pub maxval: u16,
+    // map the smallest value to 0 and the largest to maxval, otherwise every value has
+    // to lie in 0..=maxval already
+    pub rescale: bool,
+}
+
+impl Default for PnmOptions {
+    fn default() -> Self {
+        PnmOptions {
+            encoding: PnmEncoding::Binary,
+            maxval: 255,
+            rescale: false,
+        }
+    }
+}
+
+impl Matrix {
+    // netpbm
+
+    pub fn read_pgm<R: Read>(reader: R) -> Result<Self, ReadError> {
+        // grayscale, P2 or P5, one sample per pixel
+        let mut channels = read_pnm(reader, 1)?;
+
+        Ok(channels.remove(0))
+    }
+
+    pub fn write_pgm<W: Write>(&self, writer: W, options: &PnmOptions) -> io::Result<()> {
+        write_pnm(writer, &[self], options)
+    }
+}
+
+pub fn read_ppm<R: Read>(reader: R) -> Result<[Matrix; 3], ReadError> {
+    // colour, P3 or P6, the red, green and blue channels as separate matrices
+    let channels = read_pnm(reader, 3)?;
+
+    Ok(channels.try_into().unwrap())
+}
+
+pub fn write_ppm<W: Write>(
+    channels: &[Matrix; 3],
+    writer: W,
+    options: &PnmOptions,
+) -> io::Result<()> {
+    // rescaling uses the range of all three channels so the colour balance is kept
+    let [red, green, blue] = channels;
+    if red.rows() != green.rows()
+        || red.rows() != blue.rows()
+        || red.cols() != green.cols()
+        || red.cols() != blue.cols()
+    {
+        panic!(
+            "ppm channels must have the same shape, found {} x {}, {} x {} and {} x {}",
+            red.rows(),
+            red.cols(),
+            green.rows(),
+            green.cols(),
+            blue.rows(),
+            blue.cols()
+        )
+    }
+
+    write_pnm(writer, &[red, green, blue], options)
+}
+
+fn read_pnm<R: Read>(mut reader: R, depth: usize) -> Result<Vec<Matrix>, ReadError> {
+    // depth is 1 for pgm and 3 for ppm, samples of a pixel are interleaved
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+
+    let (ascii, binary) = if depth == 1 {
+        (b'2', b'5')
+    } else {
+        (b'3', b'6')
+    };
+    let encoding = match bytes.get(..2) {
+        Some([b'P', magic]) if *magic == ascii => PnmEncoding::Ascii,
+        Some([b'P', magic]) if *magic == binary => PnmEncoding::Binary,
+        _ => {
+            return Err(format_error(format!(
+                "expected a P{} or P{} header",
+                ascii as char, binary as char
+            )))
+        }
+    };
+
+    let mut pos = 2;
+    let width = header_value(&bytes, &mut pos, "width")?;
+    let height = header_value(&bytes, &mut pos, "height")?;
+    let maxval = header_value(&bytes, &mut pos, "maxval")?;
+    if width == 0 || height == 0 {
+        return Err(format_error(format!(
+            "image must have at least one pixel, found {} x {}",
+            width, height
+        )));
+    }
+    let maxval = u16::try_from(maxval)
+        .ok()
+        .filter(|&maxval| maxval > 0)
+        .ok_or_else(|| format_error(format!("maxval must be 1 to 65535, found {}", maxval)))?;
+
+    let count = width
+        .checked_mul(height)
+        .and_then(|pixels| pixels.checked_mul(depth))
+        .ok_or_else(|| format_error(format!("image of {} x {} is too large", width, height)))?;
+
+    let samples = match encoding {
+        PnmEncoding::Ascii => (0..count)
+            .map(|_| header_value(&bytes, &mut pos, "sample"))
+            .collect::<Result<Vec<_>, _>>()?,
+        PnmEncoding::Binary => {
+            // exactly one whitespace character separates maxval from the data
+            if !bytes.get(pos).is_some_and(u8::is_ascii_whitespace) {
+                return Err(format_error(
+                    "expected whitespace between maxval and the data",
+                ));
+            }
+            let start = pos + 1;
+            let size = if maxval > 255 { 2 } else { 1 };
+            let data = count
+                .checked_mul(size)
+                .and_then(|len| bytes.get(start..start.checked_add(len)?))
+                .ok_or_else(|| format_error("image data is truncated"))?;
+
+            data.chunks_exact(size)
+                .map(|sample| {
+                    if size == 1 {
+                        sample[0] as usize
+                    } else {
+                        u16::from_be_bytes([sample[0], sample[1]]) as usize
+                    }
+                })
+                .collect()
+        }
+    };
+
+    if let Some(sample) = samples.iter().find(|&&sample| sample > maxval as usize) {
+        return Err(format_error(format!(
+            "sample {} is larger than maxval {}",
+            sample, maxval
+        )));
+    }
+
+    let channels = (0..depth)
+        .map(|channel| {
+            let list = samples
+                .iter()
+                .skip(channel)
+                .step_by(depth)
+                .map(|&sample| sample as isize)
+                .collect();
+            Matrix::from_list(height, width, list)
+        })
+        .collect();
+
+    Ok(channels)
+}
+
+fn write_pnm<W: Write>(
+    mut writer: W,
+    channels: &[&Matrix],
+    options: &PnmOptions,
+) -> io::Result<()> {
+    if options.maxval == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "maxval must be at least 1",
+        ));
+    }
+    let maxval = options.maxval as isize;
+
+    let values = channels
+        .iter()
+        .flat_map(|channel| channel.cells.iter().flatten());
+    let (min, max) = values.fold((isize::MAX, isize::MIN), |(min, max), &value| {
+        (min.min(value), max.max(value))
+    });
+
+    if !options.rescale && (min < 0 || max > maxval) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "values range from {} to {}, outside 0..={}, use rescale",
+                min, max, maxval
+            ),
+        ));
+    }
+
+    // i128 keeps (value - min) * maxval from overflowing, results are rounded
+    let sample = |value: isize| -> u16 {
+        if !options.rescale {
+            value as u16
+        } else if max == min {
+            0
+        } else {
+            let range = max as i128 - min as i128;
+            let scaled = (value as i128 - min as i128) * maxval as i128;
+            ((2 * scaled + range) / (2 * range)) as u16
+        }
+    };
+
+    let (rows, cols) = (channels[0].rows(), channels[0].cols());
+    let magic = match (options.encoding, channels.len()) {
+        (PnmEncoding::Ascii, 1) => "P2",
+        (PnmEncoding::Binary, 1) => "P5",
+        (PnmEncoding::Ascii, _) => "P3",
+        (PnmEncoding::Binary, _) => "P6",
+    };
+    write!(writer, "{}\n{} {}\n{}\n", magic, cols, rows, maxval)?;
+
+    let pixels = (0..rows).flat_map(|r| (0..cols).map(move |c| (r, c)));
+    match options.encoding {
+        PnmEncoding::Ascii => {
+            // netpbm asks for lines of at most 70 characters
+            let mut line = String::new();
+            for (r, c) in pixels {
+                for channel in channels.iter() {
+                    let text = sample(channel.cells[r][c]).to_string();
+                    if !line.is_empty() && line.len() + 1 + text.len() > 70 {
+                        writeln!(writer, "{}", line)?;
+                        line.clear();
+                    }
+                    if !line.is_empty() {
+                        line.push(' ');
+                    }
+                    line.push_str(&text);
+                }
+            }
+            writeln!(writer, "{}", line)
+        }
+        PnmEncoding::Binary => {
+            let mut data = Vec::with_capacity(rows * cols * channels.len() * 2);
+            for (r, c) in pixels {
+                for channel in channels.iter() {
+                    let value = sample(channel.cells[r][c]);
+                    if options.maxval > 255 {
+                        data.extend_from_slice(&value.to_be_bytes());
+                    } else {
+                        data.push(value as u8);
+                    }
+                }
+            }
+            writer.write_all(&data)
+        }
+    }
+}
+
+fn header_value(bytes: &[u8], pos: &mut usize, name: &str) -> Result<usize, ReadError> {
+    // the next decimal number, skipping whitespace and # comments. pos is left on the
+    // character after it
+    loop {
+        match bytes.get(*pos) {
+            Some(b'#') => {
+                while !matches!(bytes.get(*pos), None | Some(b'\n' | b'\r')) {
+                    *pos += 1;
+                }
+            }
+            Some(byte) if byte.is_ascii_whitespace() => *pos += 1,
+            _ => break,
+        }
+    }
+
+    let start = *pos;
+    while bytes.get(*pos).is_some_and(u8::is_ascii_digit) {
+        *pos += 1;
+    }
+
+    let token = std::str::from_utf8(&bytes[start..*pos]).unwrap();
+    if token.is_empty() {
+        return Err(match bytes.get(*pos) {
+            Some(_) => format_error(format!("expected a number for {} at byte {}", name, start)),
+            None => format_error(format!("missing {}", name)),
+        });
+    }
+
+    token
+        .parse()
+        .map_err(|_| format_error(format!("{} `{}` is too large", name, token)))
+}
+
+fn format_error(message: impl Into<String>) -> ReadError {
+    ReadError::Format(message.into())
+}
+
+#[cfg(test)]
+#[path = "./_tests/pnm.rs"]
+mod tests;
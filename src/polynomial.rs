@@ -0,0 +1,167 @@
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+use crate::rational::Rational;
+use crate::scalar::Scalar;
+
+// polynomials are coefficient lists starting from the constant term, so index i holds
+// the coefficient of x^i
+
+impl Matrix {
+    // characteristic and minimal polynomials
+
+    pub fn characteristic_polynomial(&self) -> Result<Vec<isize>, MatrixError> {
+        // det(x I - A) by berkowitz, which only needs ring operations so every
+        // coefficient is computed exactly. the leading principal minors are grown one row
+        // and column at a time, each step multiplying by a toeplitz matrix built from
+        // R M^j C where M is the minor so far, R the new row and C the new column
+        let n = self.assert_square("Characteristic polynomial");
+        let a = &self.cells;
+
+        // highest degree first while building, the 0 x 0 minor has polynomial 1
+        let mut poly = vec![1isize];
+
+        for k in 0..n {
+            let mut toeplitz = vec![1, a[k][k].checked_neg().ok_or(MatrixError::Overflow)?];
+            let mut w: Vec<isize> = (0..k).map(|i| a[i][k]).collect();
+
+            for _ in 0..k {
+                let rw = checked_dot(&a[k][..k], &w)?;
+                toeplitz.push(rw.checked_neg().ok_or(MatrixError::Overflow)?);
+
+                w = (0..k)
+                    .map(|i| checked_dot(&a[i][..k], &w))
+                    .collect::<Result<_, _>>()?;
+            }
+
+            poly = (0..k + 2)
+                .map(|i| {
+                    (0..=i.min(k)).try_fold(0isize, |acc, j| {
+                        toeplitz[i - j]
+                            .checked_mul(poly[j])
+                            .and_then(|x| acc.checked_add(x))
+                            .ok_or(MatrixError::Overflow)
+                    })
+                })
+                .collect::<Result<_, _>>()?;
+        }
+
+        poly.reverse();
+        Ok(poly)
+    }
+
+    pub fn minimal_polynomial(&self) -> Result<Vec<isize>, MatrixError> {
+        // the first power A^d that is a rational combination of I, A, ..., A^(d - 1)
+        // gives the monic minimal polynomial. it divides the characteristic polynomial,
+        // so by gauss's lemma the coefficients come out as integers
+        let n = self.assert_square("Minimal polynomial");
+        let zero = Rational::from_integer(0);
+
+        // each reduced power keeps its pivot and which combination of powers it stands for
+        let mut basis: Vec<(Vec<Rational>, usize, Vec<Rational>)> = Vec::new();
+        let mut power: Matrix = Matrix::identity(n);
+
+        for d in 0..=n {
+            let mut v: Vec<Rational> = power
+                .cells
+                .iter()
+                .flatten()
+                .map(|&x| Rational::from_integer(x))
+                .collect();
+            let mut combo = vec![zero; d + 1];
+            combo[d] = Rational::from_integer(1);
+
+            for (bv, pivot, bc) in basis.iter() {
+                if v[*pivot] == zero {
+                    continue;
+                }
+
+                let f = v[*pivot]
+                    .checked_div(bv[*pivot])
+                    .ok_or(MatrixError::Overflow)?;
+                rational_sub_scaled(&mut v, bv, f)?;
+                rational_sub_scaled(&mut combo, bc, f)?;
+            }
+
+            match v.iter().position(|&x| x != zero) {
+                Some(pivot) => basis.push((v, pivot, combo)),
+                None => {
+                    return combo
+                        .iter()
+                        .map(|x| {
+                            debug_assert!(x.is_integer());
+                            Ok(x.numerator())
+                        })
+                        .collect();
+                }
+            }
+
+            power = checked_product(&power, self)?;
+        }
+
+        unreachable!("cayley hamilton bounds the degree of the minimal polynomial by n")
+    }
+}
+
+impl<T: Scalar> Matrix<T> {
+    pub fn evaluate_polynomial(&self, coefficients: &[T]) -> Self {
+        // p(A) by horner's rule with the regular Mul
+        let n = self.assert_square("Polynomial evaluation");
+        let scaled_identity = |c: T| Matrix::identity(n).map(|x: T| x * c);
+
+        coefficients
+            .iter()
+            .rev()
+            .fold(Matrix::zeroes(n, n), |acc, &c| {
+                acc * self.clone() + scaled_identity(c)
+            })
+    }
+}
+
+fn checked_dot(a: &[isize], b: &[isize]) -> Result<isize, MatrixError> {
+    a.iter().zip(b).try_fold(0isize, |acc, (&x, &y)| {
+        x.checked_mul(y)
+            .and_then(|xy| acc.checked_add(xy))
+            .ok_or(MatrixError::Overflow)
+    })
+}
+
+fn checked_product(a: &Matrix, b: &Matrix) -> Result<Matrix, MatrixError> {
+    let columns = b.transpose();
+    let cells = a
+        .cells
+        .iter()
+        .map(|row| {
+            columns
+                .cells
+                .iter()
+                .map(|col| checked_dot(row, col))
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Matrix::new(cells))
+}
+
+fn rational_sub_scaled(
+    target: &mut Vec<Rational>,
+    source: &[Rational],
+    f: Rational,
+) -> Result<(), MatrixError> {
+    // target -= f * source, growing target if source is longer
+    if target.len() < source.len() {
+        target.resize(source.len(), Rational::from_integer(0));
+    }
+
+    for (t, &s) in target.iter_mut().zip(source) {
+        *t = f
+            .checked_mul(s)
+            .and_then(|x| t.checked_sub(x))
+            .ok_or(MatrixError::Overflow)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "./_tests/polynomial.rs"]
+mod tests;
@@ -0,0 +1,192 @@
+use std::cmp::Ordering;
+
+use crate::error::MatrixError;
+use crate::lu::back_substitute;
+use crate::matrix::Matrix;
+use crate::scalar::Float;
+
+impl<T: Float> Matrix<T> {
+    // qr decomposition
+
+    pub fn qr(&self) -> (Self, Self) {
+        // A = Q R with Q m x m orthogonal and R m x n upper triangular
+        let (reflectors, r) = self.householder();
+        let q = apply_reflectors(&reflectors, Matrix::identity(self.rows()));
+
+        (q, r)
+    }
+
+    pub fn thin_qr(&self) -> (Self, Self) {
+        // A = Q R with Q m x n having orthonormal columns and R n x n upper triangular
+        if self.rows() < self.cols() {
+            panic!(
+                "Thin QR decomposition needs at least as many rows as columns. Found {} x {}",
+                self.rows(),
+                self.cols()
+            )
+        }
+
+        let (reflectors, mut r) = self.householder();
+        r.cells.truncate(self.cols());
+
+        let q = Matrix::new(
+            (0..self.rows())
+                .map(|row| {
+                    (0..self.cols())
+                        .map(|c| if row == c { T::one() } else { T::zero() })
+                        .collect()
+                })
+                .collect(),
+        );
+
+        (apply_reflectors(&reflectors, q), r)
+    }
+
+    pub fn least_squares(&self, b: &Self) -> Result<Self, MatrixError> {
+        // minimises |A x - b| for each column of b, Q^T b is built by applying the
+        // reflectors directly so Q is never formed
+        if self.rows() < self.cols() {
+            panic!(
+                "Least squares needs at least as many rows as columns. Found {} x {}",
+                self.rows(),
+                self.cols()
+            )
+        }
+
+        if b.rows() != self.rows() {
+            panic!(
+                "Right hand side must have {} rows to solve. Found {} x {}",
+                self.rows(),
+                b.rows(),
+                b.cols()
+            )
+        }
+
+        let n = self.cols();
+        let (reflectors, r) = self.householder();
+
+        let mut y = b.clone();
+        let cols = y.cols();
+        for (k, v) in reflectors.iter().enumerate() {
+            reflect_rows(&mut y.cells, v, k, 0, cols);
+        }
+
+        // a diagonal entry of R this small relative to the largest means A is rank deficient
+        let largest = (0..n).fold(T::zero(), |acc, i| {
+            if r.cells[i][i].abs() > acc {
+                r.cells[i][i].abs()
+            } else {
+                acc
+            }
+        });
+        let tol = largest * T::epsilon() * T::from_f64(self.rows() as f64);
+
+        let mut x = y.cells;
+        x.truncate(n);
+
+        if (0..n).any(|i| r.cells[i][i].abs().partial_cmp(&tol) != Some(Ordering::Greater)) {
+            return Err(MatrixError::Singular);
+        }
+
+        back_substitute(&mut x, |i, k| r.cells[i][k]);
+
+        Ok(Matrix::new(x))
+    }
+
+    fn householder(&self) -> (Vec<Vec<T>>, Self) {
+        // reduces A to R in place, the k-th reflector is I - 2 v v^T / (v^T v) acting on
+        // rows k.. and an empty v marks a column that was already zero
+        let (m, n) = (self.rows(), self.cols());
+        let mut r = self.clone();
+        let mut reflectors = Vec::new();
+
+        for k in 0..n.min(m.saturating_sub(1)) {
+            let x: Vec<T> = (k..m).map(|i| r.cells[i][k]).collect();
+            let (v, alpha) = householder_vector(&x);
+
+            if v.is_empty() {
+                reflectors.push(v);
+                continue;
+            }
+
+            reflect_rows(&mut r.cells, &v, k, k, n);
+
+            r.cells[k][k] = alpha;
+            for i in (k + 1)..m {
+                r.cells[i][k] = T::zero();
+            }
+
+            reflectors.push(v);
+        }
+
+        (reflectors, r)
+    }
+}
+
+fn apply_reflectors<T: Float>(reflectors: &[Vec<T>], mut q: Matrix<T>) -> Matrix<T> {
+    // Q = H_0 H_1 ... H_k applied to q, the last reflector goes first
+    let cols = q.cols();
+    for (k, v) in reflectors.iter().enumerate().rev() {
+        reflect_rows(&mut q.cells, v, k, 0, cols);
+    }
+
+    q
+}
+
+pub(crate) fn householder_vector<T: Float>(x: &[T]) -> (Vec<T>, T) {
+    // v with (I - 2 v v^T / (v^T v)) x = alpha e1, v is empty when x is already zero
+    let norm = x.iter().fold(T::zero(), |acc, &a| acc + a * a).sqrt();
+
+    if norm == T::zero() {
+        return (Vec::new(), T::zero());
+    }
+
+    // reflect onto -sign(x0) |x| e1 so v0 never suffers cancellation
+    let alpha = if x[0] > T::zero() { -norm } else { norm };
+    let mut v = x.to_vec();
+    v[0] = v[0] - alpha;
+
+    (v, alpha)
+}
+
+// works down columns of a, one entry from each row
+#[allow(clippy::needless_range_loop)]
+pub(crate) fn reflect_rows<T: Float>(a: &mut [Vec<T>], v: &[T], r0: usize, c0: usize, c1: usize) {
+    // rows r0.. <- H rows r0.., only touching columns c0..c1
+    if v.is_empty() {
+        return;
+    }
+
+    let two = T::one() + T::one();
+    let vv = v.iter().fold(T::zero(), |acc, &x| acc + x * x);
+
+    for c in c0..c1 {
+        let s = (0..v.len()).fold(T::zero(), |acc, i| acc + v[i] * a[r0 + i][c]);
+        let f = two * s / vv;
+        for i in 0..v.len() {
+            a[r0 + i][c] = a[r0 + i][c] - f * v[i];
+        }
+    }
+}
+
+pub(crate) fn reflect_cols<T: Float>(a: &mut [Vec<T>], v: &[T], c0: usize, r0: usize, r1: usize) {
+    // columns c0.. <- columns c0.. H, only touching rows r0..r1
+    if v.is_empty() {
+        return;
+    }
+
+    let two = T::one() + T::one();
+    let vv = v.iter().fold(T::zero(), |acc, &x| acc + x * x);
+
+    for row in a[r0..r1].iter_mut() {
+        let s = (0..v.len()).fold(T::zero(), |acc, i| acc + v[i] * row[c0 + i]);
+        let f = two * s / vv;
+        for i in 0..v.len() {
+            row[c0 + i] = row[c0 + i] - f * v[i];
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "./_tests/qr.rs"]
+mod tests;
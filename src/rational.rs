@@ -0,0 +1,203 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::scalar::{Field, Scalar};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Rational {
+    // always kept in lowest terms with a positive denominator, so the derived Eq is exact
+    num: isize,
+    den: isize,
+}
+
+impl Rational {
+    pub fn new(num: isize, den: isize) -> Self {
+        if den == 0 {
+            panic!("denominator of a Rational must be non zero")
+        }
+
+        Self::checked_new(num, den).expect("Rational overflowed while normalising")
+    }
+
+    pub fn checked_new(num: isize, den: isize) -> Option<Self> {
+        reduce(num as i128, den as i128)
+    }
+
+    pub fn from_integer(num: isize) -> Self {
+        Rational { num, den: 1 }
+    }
+
+    pub fn numerator(&self) -> isize {
+        self.num
+    }
+
+    pub fn denominator(&self) -> isize {
+        self.den
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.den == 1
+    }
+
+    pub fn recip(&self) -> Option<Self> {
+        reduce(self.den as i128, self.num as i128)
+    }
+
+    pub fn checked_add(self, b: Self) -> Option<Self> {
+        let (n1, d1, n2, d2) = self.widen(b);
+        reduce(n1 * d2 + n2 * d1, d1 * d2)
+    }
+
+    pub fn checked_sub(self, b: Self) -> Option<Self> {
+        let (n1, d1, n2, d2) = self.widen(b);
+        reduce(n1 * d2 - n2 * d1, d1 * d2)
+    }
+
+    pub fn checked_mul(self, b: Self) -> Option<Self> {
+        let (n1, d1, n2, d2) = self.widen(b);
+        reduce(n1 * n2, d1 * d2)
+    }
+
+    pub fn checked_div(self, b: Self) -> Option<Self> {
+        let (n1, d1, n2, d2) = self.widen(b);
+        reduce(n1 * d2, d1 * n2)
+    }
+
+    fn widen(self, b: Self) -> (i128, i128, i128, i128) {
+        // every product of two isize fits in an i128, so the ops only overflow when narrowing back
+        (
+            self.num as i128,
+            self.den as i128,
+            b.num as i128,
+            b.den as i128,
+        )
+    }
+}
+
+fn reduce(num: i128, den: i128) -> Option<Rational> {
+    if den == 0 {
+        return None;
+    }
+
+    let g = gcd(num, den);
+    let (num, den) = if den < 0 {
+        (-num / g, -den / g)
+    } else {
+        (num / g, den / g)
+    };
+
+    Some(Rational {
+        num: num.try_into().ok()?,
+        den: den.try_into().ok()?,
+    })
+}
+
+pub(crate) fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a as i128
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, b: Self) -> Self::Output {
+        self.checked_add(b)
+            .expect("attempt to add Rationals with overflow")
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, b: Self) -> Self::Output {
+        self.checked_sub(b)
+            .expect("attempt to subtract Rationals with overflow")
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, b: Self) -> Self::Output {
+        self.checked_mul(b)
+            .expect("attempt to multiply Rationals with overflow")
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    fn div(self, b: Self) -> Self::Output {
+        if b.num == 0 {
+            panic!("attempt to divide a Rational by zero")
+        }
+
+        self.checked_div(b)
+            .expect("attempt to divide Rationals with overflow")
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Rational {
+            num: self
+                .num
+                .checked_neg()
+                .expect("attempt to negate Rational with overflow"),
+            den: self.den,
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (n1, d1, n2, d2) = self.widen(*other);
+        (n1 * d2).cmp(&(n2 * d1))
+    }
+}
+
+impl From<isize> for Rational {
+    fn from(num: isize) -> Self {
+        Rational::from_integer(num)
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+impl Scalar for Rational {
+    fn zero() -> Self {
+        Rational::from_integer(0)
+    }
+
+    fn one() -> Self {
+        Rational::from_integer(1)
+    }
+}
+
+impl Field for Rational {}
+
+#[cfg(test)]
+#[path = "./_tests/rational.rs"]
+mod tests;
@@ -0,0 +1,81 @@
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+pub trait Scalar:
+    Copy + Debug + PartialEq + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    // element type of a Matrix, a commutative ring with the usual operators
+    fn zero() -> Self;
+    fn one() -> Self;
+}
+
+pub trait Field: Scalar + Div<Output = Self> + Neg<Output = Self> {
+    // a Scalar where every non zero element can be divided by, used for elimination
+}
+
+pub trait Float: Scalar + PartialOrd + Div<Output = Self> + Neg<Output = Self> {
+    // f32 and f64, kept apart from Field since exact elimination is not stable for them
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn is_nan(self) -> bool;
+    fn epsilon() -> Self;
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_scalar_int {
+    ($($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn one() -> Self {
+                    1
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_int!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_scalar_float {
+    ($($t:ident),*) => {
+        $(
+            impl Scalar for $t {
+                fn zero() -> Self {
+                    0.0
+                }
+
+                fn one() -> Self {
+                    1.0
+                }
+            }
+
+            impl Float for $t {
+                fn abs(self) -> Self {
+                    $t::abs(self)
+                }
+
+                fn sqrt(self) -> Self {
+                    $t::sqrt(self)
+                }
+
+                fn is_nan(self) -> bool {
+                    $t::is_nan(self)
+                }
+
+                fn epsilon() -> Self {
+                    $t::EPSILON
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    value as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_float!(f32, f64);
@@ -0,0 +1,278 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::ops::{Add, Mul, Sub};
+
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+
+pub trait Semiring {
+    // the `+` and `*` used by a matrix product, `accumulate` takes the place of `+`
+    // with `zero` as its identity and `combine` the place of `*` with `one` as its identity
+    type Elem: Copy + Debug + PartialEq;
+
+    fn zero() -> Self::Elem;
+    fn one() -> Self::Elem;
+    fn combine(a: Self::Elem, b: Self::Elem) -> Self::Elem;
+    fn accumulate(a: Self::Elem, b: Self::Elem) -> Self::Elem;
+}
+
+/// A [`Semiring`] with additive inverses, which is what strassen needs.
+///
+/// Only rings can use [`Matrix::semiring_strass`], so asking for it over a tropical
+/// semiring does not compile:
+///
+/// ```compile_fail
+/// use matrix_rs::matrix::Matrix;
+/// use matrix_rs::semiring::MinPlus;
+///
+/// let a = Matrix::new(vec![vec![0, 1], vec![1, 0]]);
+/// a.semiring_strass::<MinPlus>(&a);
+/// ```
+pub trait Ring: Semiring {
+    fn subtract(a: Self::Elem, b: Self::Elem) -> Self::Elem;
+}
+
+pub struct Arithmetic<T>(PhantomData<T>);
+
+impl<T: Scalar> Semiring for Arithmetic<T> {
+    // the usual + and *, mostly useful to check the other instances against
+    type Elem = T;
+
+    fn zero() -> T {
+        T::zero()
+    }
+
+    fn one() -> T {
+        T::one()
+    }
+
+    fn combine(a: T, b: T) -> T {
+        a * b
+    }
+
+    fn accumulate(a: T, b: T) -> T {
+        a + b
+    }
+}
+
+impl<T: Scalar> Ring for Arithmetic<T> {
+    fn subtract(a: T, b: T) -> T {
+        a - b
+    }
+}
+
+pub struct MinPlus;
+
+impl Semiring for MinPlus {
+    // shortest paths, isize::MAX stands in for a missing edge
+    type Elem = isize;
+
+    fn zero() -> isize {
+        isize::MAX
+    }
+
+    fn one() -> isize {
+        0
+    }
+
+    fn combine(a: isize, b: isize) -> isize {
+        if a == isize::MAX || b == isize::MAX {
+            isize::MAX
+        } else {
+            a.saturating_add(b)
+        }
+    }
+
+    fn accumulate(a: isize, b: isize) -> isize {
+        a.min(b)
+    }
+}
+
+pub struct MaxPlus;
+
+impl Semiring for MaxPlus {
+    // longest paths and scheduling, isize::MIN stands in for a missing edge
+    type Elem = isize;
+
+    fn zero() -> isize {
+        isize::MIN
+    }
+
+    fn one() -> isize {
+        0
+    }
+
+    fn combine(a: isize, b: isize) -> isize {
+        if a == isize::MIN || b == isize::MIN {
+            isize::MIN
+        } else {
+            a.saturating_add(b)
+        }
+    }
+
+    fn accumulate(a: isize, b: isize) -> isize {
+        a.max(b)
+    }
+}
+
+pub struct Boolean;
+
+impl Semiring for Boolean {
+    // reachability
+    type Elem = bool;
+
+    fn zero() -> bool {
+        false
+    }
+
+    fn one() -> bool {
+        true
+    }
+
+    fn combine(a: bool, b: bool) -> bool {
+        a && b
+    }
+
+    fn accumulate(a: bool, b: bool) -> bool {
+        a || b
+    }
+}
+
+pub struct MaxMin;
+
+impl Semiring for MaxMin {
+    // bottleneck (widest) paths, isize::MIN stands in for a missing edge
+    type Elem = isize;
+
+    fn zero() -> isize {
+        isize::MIN
+    }
+
+    fn one() -> isize {
+        isize::MAX
+    }
+
+    fn combine(a: isize, b: isize) -> isize {
+        a.min(b)
+    }
+
+    fn accumulate(a: isize, b: isize) -> isize {
+        a.max(b)
+    }
+}
+
+impl<T: Copy + Debug + PartialEq> Matrix<T> {
+    // semiring products
+
+    pub fn semiring_identity<S: Semiring<Elem = T>>(size: usize) -> Self {
+        let cells = (0..size)
+            .map(|r| {
+                (0..size)
+                    .map(|c| if r == c { S::one() } else { S::zero() })
+                    .collect()
+            })
+            .collect();
+
+        Matrix::new(cells)
+    }
+
+    pub fn semiring_mul<S: Semiring<Elem = T>>(&self, b: &Self) -> Self {
+        if self.cols() != b.rows() {
+            panic!("Matrix multiplaicaton only allowed for r x m and m x c matrices to form r x c matrix.\n Found {} x {} and {} x {} matrices", self.rows(), self.cols(), b.rows(), b.cols())
+        }
+
+        let mut out = Matrix::new(vec![vec![S::zero(); b.cols()]; self.rows()]);
+
+        for (r, row) in out.cells.iter_mut().enumerate() {
+            for (c, cell) in row.iter_mut().enumerate() {
+                for idx in 1..=self.cols() {
+                    *cell = S::accumulate(*cell, S::combine(self[[r + 1, idx]], b[[idx, c + 1]]));
+                }
+            }
+        }
+
+        out
+    }
+
+    pub fn semiring_strass<S: Ring<Elem = T>>(&self, b: &Self) -> Self {
+        // wraps each element so the ring operations become Scalar ones and the
+        // regular strass can be reused unchanged
+        let a = self.map(InRing::<S>::new);
+        let b = b.map(InRing::<S>::new);
+
+        a.strass(&b).map(|cell| cell.value)
+    }
+}
+
+struct InRing<S: Ring> {
+    value: S::Elem,
+    ring: PhantomData<S>,
+}
+
+impl<S: Ring> InRing<S> {
+    fn new(value: S::Elem) -> Self {
+        InRing {
+            value,
+            ring: PhantomData,
+        }
+    }
+}
+
+// derives would ask for S itself to be Copy, Debug and PartialEq
+impl<S: Ring> Clone for InRing<S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: Ring> Copy for InRing<S> {}
+
+impl<S: Ring> Debug for InRing<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<S: Ring> PartialEq for InRing<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<S: Ring> Add for InRing<S> {
+    type Output = Self;
+
+    fn add(self, b: Self) -> Self::Output {
+        InRing::new(S::accumulate(self.value, b.value))
+    }
+}
+
+impl<S: Ring> Sub for InRing<S> {
+    type Output = Self;
+
+    fn sub(self, b: Self) -> Self::Output {
+        InRing::new(S::subtract(self.value, b.value))
+    }
+}
+
+impl<S: Ring> Mul for InRing<S> {
+    type Output = Self;
+
+    fn mul(self, b: Self) -> Self::Output {
+        InRing::new(S::combine(self.value, b.value))
+    }
+}
+
+impl<S: Ring> Scalar for InRing<S> {
+    fn zero() -> Self {
+        InRing::new(S::zero())
+    }
+
+    fn one() -> Self {
+        InRing::new(S::one())
+    }
+}
+
+#[cfg(test)]
+#[path = "./_tests/semiring.rs"]
+mod tests;
@@ -0,0 +1,150 @@
+use serde::de::Error;
+use serde::ser::{SerializeSeq, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::matrix::{Blocks, Matrix};
+use crate::scalar::Scalar;
+
+// the default encoding is {rows, cols, data} with data in row major order, `nested` holds
+// the alternative [[..], ..] encoding for use with #[serde(with = "...")]. either way the
+// shape is checked before a Matrix is built, so no payload can reach the panics in
+// Matrix::new
+
+impl<T: Serialize> Serialize for Matrix<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_compact(&self.cells, serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Matrix<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let compact = Compact::deserialize(deserializer)?;
+        let cells = compact.into_cells().map_err(D::Error::custom)?;
+
+        Ok(Matrix::new(cells))
+    }
+}
+
+impl<T: Scalar + Serialize> Serialize for Blocks<T> {
+    // blocks are always 2 x 2, so they are written as the matrix they tile
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_compact(&self.clone().to_matrix().cells, serializer)
+    }
+}
+
+impl<'de, T: Scalar + Deserialize<'de>> Deserialize<'de> for Blocks<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let compact = Compact::deserialize(deserializer)?;
+
+        if !compact.rows.is_multiple_of(2) || !compact.cols.is_multiple_of(2) {
+            return Err(D::Error::custom(format!(
+                "blocks need an even number of rows and columns, found {} x {}",
+                compact.rows, compact.cols
+            )));
+        }
+
+        let cells = compact.into_cells().map_err(D::Error::custom)?;
+
+        Ok(Blocks::from_matrix(Matrix::new(cells)))
+    }
+}
+
+pub mod nested {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::matrix::Matrix;
+
+    pub fn serialize<T: Serialize, S: Serializer>(
+        matrix: &Matrix<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        matrix.cells.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Matrix<T>, D::Error> {
+        let cells: Vec<Vec<T>> = Vec::deserialize(deserializer)?;
+
+        let cols = match cells.first() {
+            Some(row) if !row.is_empty() => row.len(),
+            _ => {
+                return Err(D::Error::custom(
+                    "matrix must have at least one row and column",
+                ))
+            }
+        };
+
+        if let Some(idx) = cells.iter().position(|row| row.len() != cols) {
+            return Err(D::Error::custom(format!(
+                "row {} has {} entries, expected {}",
+                idx + 1,
+                cells[idx].len(),
+                cols
+            )));
+        }
+
+        Ok(Matrix::new(cells))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Compact<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T> Compact<T> {
+    fn into_cells(self) -> Result<Vec<Vec<T>>, String> {
+        if self.rows == 0 || self.cols == 0 {
+            return Err("matrix must have at least one row and column".to_string());
+        }
+
+        if self.rows.checked_mul(self.cols) != Some(self.data.len()) {
+            return Err(format!(
+                "a {} x {} matrix needs {} entries, found {}",
+                self.rows,
+                self.cols,
+                self.rows.saturating_mul(self.cols),
+                self.data.len()
+            ));
+        }
+
+        let mut data = self.data.into_iter();
+        Ok((0..self.rows)
+            .map(|_| data.by_ref().take(self.cols).collect())
+            .collect())
+    }
+}
+
+fn serialize_compact<T: Serialize, S: Serializer>(
+    cells: &[Vec<T>],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    struct Flat<'a, T>(&'a [Vec<T>]);
+
+    impl<T: Serialize> Serialize for Flat<'_, T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            // an explicit length, formats such as bincode need it up front
+            let len = self.0.iter().map(Vec::len).sum();
+            let mut seq = serializer.serialize_seq(Some(len))?;
+            for cell in self.0.iter().flatten() {
+                seq.serialize_element(cell)?;
+            }
+            seq.end()
+        }
+    }
+
+    let mut state = serializer.serialize_struct("Matrix", 3)?;
+    state.serialize_field("rows", &cells.len())?;
+    state.serialize_field("cols", &cells.first().map_or(0, Vec::len))?;
+    state.serialize_field("data", &Flat(cells))?;
+    state.end()
+}
+
+#[cfg(test)]
+#[path = "./_tests/serialize.rs"]
+mod tests;
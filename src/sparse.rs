@@ -0,0 +1,116 @@
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CsrMatrix<T> {
+    // compressed sparse rows, row r (from 0) holds the entries at row_ptr[r]..row_ptr[r + 1]
+    // of col_idx and values, sorted by column. explicitly stored zeros are kept
+    rows: usize,
+    cols: usize,
+    row_ptr: Vec<usize>,
+    col_idx: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T: Scalar> CsrMatrix<T> {
+    pub fn from_triplets(rows: usize, cols: usize, mut triplets: Vec<([usize; 2], T)>) -> Self {
+        // 1 based [row, col] indices like Matrix, a position given twice keeps the last
+        // value, as it would when writing into a dense matrix
+        if rows == 0 || cols == 0 {
+            panic!("a sparse matrix must have at least one row and column")
+        }
+        if let Some(&([r, c], _)) = triplets
+            .iter()
+            .find(|([r, c], _)| !(1..=rows).contains(r) || !(1..=cols).contains(c))
+        {
+            panic!(
+                "index [{}, {}] is out of bounds for a {} x {} matrix",
+                r, c, rows, cols
+            )
+        }
+
+        // stable, so the last of several values for one position stays last
+        triplets.sort_by_key(|&(index, _)| index);
+
+        let mut row_ptr = vec![0; rows + 1];
+        let mut col_idx: Vec<usize> = Vec::with_capacity(triplets.len());
+        let mut values: Vec<T> = Vec::with_capacity(triplets.len());
+        let mut last = None;
+
+        for ([r, c], value) in triplets {
+            if last == Some([r, c]) {
+                *values.last_mut().unwrap() = value;
+                continue;
+            }
+
+            row_ptr[r] += 1;
+            col_idx.push(c - 1);
+            values.push(value);
+            last = Some([r, c]);
+        }
+
+        for r in 0..rows {
+            row_ptr[r + 1] += row_ptr[r];
+        }
+
+        CsrMatrix {
+            rows,
+            cols,
+            row_ptr,
+            col_idx,
+            values,
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn nnz(&self) -> usize {
+        // the number of stored entries
+        self.values.len()
+    }
+
+    pub fn get(&self, index: [usize; 2]) -> T {
+        let [r, c] = index;
+        if r == 0 || r > self.rows || c == 0 || c > self.cols {
+            panic!(
+                "index [{}, {}] is out of bounds for a {} x {} matrix",
+                r, c, self.rows, self.cols
+            )
+        }
+
+        let range = self.row_ptr[r - 1]..self.row_ptr[r];
+        match self.col_idx[range.clone()].binary_search(&(c - 1)) {
+            Ok(at) => self.values[range.start + at],
+            Err(_) => T::zero(),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ([usize; 2], T)> + '_ {
+        // the stored entries in row major order, with 1 based indices
+        (0..self.rows).flat_map(move |r| {
+            (self.row_ptr[r]..self.row_ptr[r + 1])
+                .map(move |at| ([r + 1, self.col_idx[at] + 1], self.values[at]))
+        })
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T> {
+        // needs memory for every rows x cols entry however few are stored
+        let mut out = Matrix::zeroes(self.rows, self.cols);
+
+        for ([r, c], value) in self.iter() {
+            out.cells[r - 1][c - 1] = value;
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+#[path = "./_tests/sparse.rs"]
+mod tests;
@@ -0,0 +1,177 @@
+use std::cmp::Ordering;
+
+use crate::eigen::jacobi_rotation;
+use crate::matrix::Matrix;
+use crate::scalar::Float;
+
+// one sided jacobi converges quadratically, so this is only hit by NaN input
+const SVD_MAX_SWEEPS: usize = 100;
+
+impl<T: Float> Matrix<T> {
+    // singular value decomposition
+
+    pub fn svd(&self) -> (Self, Vec<T>, Self) {
+        // thin svd A = U diag(sigma) V^T with k = min(rows, cols), U is rows x k, sigma is
+        // sorted in descending order and V^T is k x cols
+        if self.rows() < self.cols() {
+            let (u, sigma, vt) = self.transpose().svd();
+            return (vt.transpose(), sigma, u.transpose());
+        }
+
+        let (m, n) = (self.rows(), self.cols());
+
+        // works on columns, so keep them as rows of the transpose
+        let mut u = self.transpose().cells;
+        let mut v = Matrix::<T>::identity(n).cells;
+
+        for _ in 0..SVD_MAX_SWEEPS {
+            let mut rotated = false;
+
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    let alpha = dot(&u[p], &u[p]);
+                    let beta = dot(&u[q], &u[q]);
+                    let gamma = dot(&u[p], &u[q]);
+
+                    if gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() {
+                        continue;
+                    }
+
+                    rotated = true;
+                    let (c, s) = jacobi_rotation(alpha, beta, gamma);
+                    rotate(&mut u, p, q, c, s);
+                    rotate(&mut v, p, q, c, s);
+                }
+            }
+
+            if !rotated {
+                break;
+            }
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        let norms: Vec<T> = u.iter().map(|col| dot(col, col).sqrt()).collect();
+        order.sort_by(|&x, &y| norms[y].partial_cmp(&norms[x]).unwrap_or(Ordering::Equal));
+
+        let sigma: Vec<T> = order.iter().map(|&j| norms[j]).collect();
+        let tol = sigma[0] * T::epsilon() * T::from_f64(m as f64);
+
+        // columns of U for (numerically) zero singular values are filled in afterwards
+        let mut u_cols: Vec<Vec<T>> = Vec::with_capacity(n);
+        let mut missing = Vec::new();
+        for (idx, &j) in order.iter().enumerate() {
+            if norms[j] > tol {
+                u_cols.push(u[j].iter().map(|&x| x / norms[j]).collect());
+            } else {
+                u_cols.push(vec![T::zero(); m]);
+                missing.push(idx);
+            }
+        }
+        complete_orthonormal(&mut u_cols, &missing);
+
+        let vt = Matrix::new(order.iter().map(|&j| v[j].clone()).collect());
+
+        (Matrix::new(u_cols).transpose(), sigma, vt)
+    }
+
+    pub fn singular_values(&self) -> Vec<T> {
+        self.svd().1
+    }
+
+    pub fn pseudo_inverse(&self, rcond: T) -> Self {
+        // singular values at most rcond times the largest are treated as zero
+        let (u, sigma, vt) = self.svd();
+        let cutoff = rcond * sigma.first().copied().unwrap_or(T::zero());
+
+        let mut out = Matrix::zeroes(self.cols(), self.rows());
+        for (k, &s) in sigma.iter().enumerate() {
+            if s <= cutoff || s == T::zero() {
+                continue;
+            }
+
+            for i in 0..self.cols() {
+                let scaled = vt.cells[k][i] / s;
+                for j in 0..self.rows() {
+                    out.cells[i][j] = out.cells[i][j] + scaled * u.cells[j][k];
+                }
+            }
+        }
+
+        out
+    }
+
+    pub fn rank_numerical(&self, tol: T) -> usize {
+        // number of singular values strictly above the absolute tolerance tol
+        self.singular_values().iter().filter(|&&s| s > tol).count()
+    }
+
+    pub fn condition_number(&self) -> T {
+        // ratio of the largest to the smallest singular value, infinite when rank
+        // deficient, that is when the smallest is lost in the rounding error of the largest
+        let sigma = self.singular_values();
+        let eps = T::epsilon() * T::from_f64(self.rows().max(self.cols()) as f64);
+
+        match (sigma.first(), sigma.last()) {
+            (Some(&largest), Some(&smallest)) if smallest > largest * eps => largest / smallest,
+            _ => T::from_f64(f64::INFINITY),
+        }
+    }
+
+    pub fn norm_2(&self) -> T {
+        // the spectral norm, the largest singular value
+        self.singular_values().first().copied().unwrap_or(T::zero())
+    }
+}
+
+fn dot<T: Float>(a: &[T], b: &[T]) -> T {
+    a.iter().zip(b).fold(T::zero(), |acc, (&x, &y)| acc + x * y)
+}
+
+fn rotate<T: Float>(cols: &mut [Vec<T>], p: usize, q: usize, c: T, s: T) {
+    let (head, tail) = cols.split_at_mut(q);
+    for (x, y) in head[p].iter_mut().zip(tail[0].iter_mut()) {
+        let (xp, xq) = (*x, *y);
+        *x = c * xp - s * xq;
+        *y = s * xp + c * xq;
+    }
+}
+
+fn complete_orthonormal<T: Float>(cols: &mut [Vec<T>], missing: &[usize]) {
+    // replaces the listed columns with unit vectors orthogonal to every other column, each
+    // is the standard basis vector with the largest part left after projecting the others
+    // out (twice, for stability)
+    let m = cols.first().map_or(0, |col| col.len());
+
+    for &idx in missing {
+        let mut best = (T::zero(), vec![T::zero(); m]);
+
+        for candidate in 0..m {
+            let mut e = vec![T::zero(); m];
+            e[candidate] = T::one();
+
+            for _ in 0..2 {
+                for (j, col) in cols.iter().enumerate() {
+                    if j == idx {
+                        continue;
+                    }
+                    let proj = dot(&e, col);
+                    for (x, &y) in e.iter_mut().zip(col) {
+                        *x = *x - proj * y;
+                    }
+                }
+            }
+
+            let norm = dot(&e, &e).sqrt();
+            if norm > best.0 {
+                best = (norm, e);
+            }
+        }
+
+        let (norm, e) = best;
+        cols[idx] = e.iter().map(|&x| x / norm).collect();
+    }
+}
+
+#[cfg(test)]
+#[path = "./_tests/svd.rs"]
+mod tests;
@@ -0,0 +1,218 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::scalar::{Field, Scalar};
+
+/// Integers modulo `P`.
+///
+/// Any `P` of at least 2 gives a ring, but division and with it the [`Field`]
+/// elimination behind `determinant` and `inverse` need `P` to be prime. A composite
+/// modulus is rejected when the matrix is compiled, use [`Matrix::inverse_mod`]
+/// instead:
+///
+/// ```compile_fail
+/// use matrix_rs::matrix::Matrix;
+/// use matrix_rs::zp::Zp;
+///
+/// let a = Matrix::new(vec![vec![Zp::<12>::new(2), Zp::new(1)], vec![Zp::new(1), Zp::new(0)]]);
+/// a.inverse();
+/// ```
+///
+/// [`Matrix::inverse_mod`]: crate::matrix::Matrix::inverse_mod
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Zp<const P: u64> {
+    // integer modulo P, always reduced into 0..P
+    value: u64,
+}
+
+impl<const P: u64> Zp<P> {
+    const MODULUS_CHECK: () = assert!(P > 1, "modulus of Zp must be at least 2");
+    const PRIME_CHECK: () = assert!(is_prime(P), "division in Zp needs a prime modulus");
+
+    pub fn new(value: u64) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::MODULUS_CHECK;
+
+        Zp { value: value % P }
+    }
+
+    pub fn from_signed(value: i64) -> Self {
+        // in i128 since P may not fit in an i64
+        Zp::new((value as i128).rem_euclid(P as i128) as u64)
+    }
+
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    pub fn pow(self, mut exp: u64) -> Self {
+        let mut base = self;
+        let mut out = Zp::new(1);
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                out = out * base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+
+        out
+    }
+
+    pub fn inv(self) -> Option<Self> {
+        // extended euclid rather than fermat so a composite P just reports non units
+        let (mut old_r, mut r) = (self.value as i128, P as i128);
+        let (mut old_s, mut s) = (1i128, 0i128);
+
+        while r != 0 {
+            let q = old_r / r;
+            (old_r, r) = (r, old_r - q * r);
+            (old_s, s) = (s, old_s - q * s);
+        }
+
+        if old_r == 1 {
+            Some(Zp::new(old_s.rem_euclid(P as i128) as u64))
+        } else {
+            None
+        }
+    }
+}
+
+impl<const P: u64> Add for Zp<P> {
+    type Output = Self;
+
+    fn add(self, b: Self) -> Self::Output {
+        Zp::new(((self.value as u128 + b.value as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> Sub for Zp<P> {
+    type Output = Self;
+
+    fn sub(self, b: Self) -> Self::Output {
+        self + (-b)
+    }
+}
+
+impl<const P: u64> Mul for Zp<P> {
+    type Output = Self;
+
+    fn mul(self, b: Self) -> Self::Output {
+        Zp::new(((self.value as u128 * b.value as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> Div for Zp<P> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, b: Self) -> Self::Output {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::PRIME_CHECK;
+
+        let inv = b
+            .inv()
+            .unwrap_or_else(|| panic!("{} has no inverse modulo {}", b.value, P));
+
+        self * inv
+    }
+}
+
+impl<const P: u64> Neg for Zp<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        if self.value == 0 {
+            self
+        } else {
+            Zp::new(P - self.value)
+        }
+    }
+}
+
+impl<const P: u64> From<u64> for Zp<P> {
+    fn from(value: u64) -> Self {
+        Zp::new(value)
+    }
+}
+
+impl<const P: u64> fmt::Display for Zp<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<const P: u64> Scalar for Zp<P> {
+    fn zero() -> Self {
+        Zp::new(0)
+    }
+
+    fn one() -> Self {
+        Zp::new(1)
+    }
+}
+
+// only a field when P is prime, Div refuses to compile for anything else
+impl<const P: u64> Field for Zp<P> {}
+
+const fn is_prime(n: u64) -> bool {
+    // deterministic miller rabin, these bases are enough for every u64
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 2 {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < BASES.len() {
+        if n.is_multiple_of(BASES[i]) {
+            return n == BASES[i];
+        }
+        i += 1;
+    }
+
+    let (mut d, mut s) = (n - 1, 0);
+    while d.is_multiple_of(2) {
+        d /= 2;
+        s += 1;
+    }
+
+    let mut i = 0;
+    while i < BASES.len() {
+        let mut x = pow_mod(BASES[i], d, n);
+        let mut r = 1;
+        while x != 1 && x != n - 1 && r < s {
+            x = mul_mod(x, x, n);
+            r += 1;
+        }
+        if x != 1 && x != n - 1 {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+const fn mul_mod(a: u64, b: u64, n: u64) -> u64 {
+    (a as u128 * b as u128 % n as u128) as u64
+}
+
+const fn pow_mod(mut base: u64, mut exp: u64, n: u64) -> u64 {
+    let mut out = 1;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            out = mul_mod(out, base, n);
+        }
+        base = mul_mod(base, base, n);
+        exp >>= 1;
+    }
+
+    out
+}
+
+#[cfg(test)]
+#[path = "./_tests/zp.rs"]
+mod tests;